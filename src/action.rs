@@ -2,6 +2,6 @@ use super::*;
 
 #[derive(Debug, PartialEq, Eq)]
 pub(crate) enum Action {
-  FocusPane(Pane),
+  FocusPane(Box<Pane>),
   Quit,
 }
@@ -8,19 +8,36 @@ struct KeyBinding {
 
 #[derive(Debug)]
 pub(crate) struct App {
+  bookmarks: Bookmarks,
+  casts: BTreeMap<String, CastRecorder<File>>,
   config: Config,
+  error: Option<String>,
   help_visible: bool,
+  highlighter: Option<ChangeHighlighter>,
+  history: PaneHistory,
+  history_view: Option<HistoryView>,
+  jump_labels: Option<JumpLabels>,
   last_refresh: Instant,
-  pane_regions: Vec<Rect>,
+  list_view: ListView,
+  marking: bool,
+  pane_regions: Vec<(String, Rect)>,
+  picker: Option<Picker>,
+  prompt: Option<Prompt>,
+  recorder: Recorder,
   selected_pane: Option<Pane>,
+  sidebar: Sidebar,
+  sidebar_regions: Vec<(SidebarNode, Rect)>,
   terminal: TerminalGuard,
   tmux: Tmux,
+  tracker: PaneTracker,
+  view: View,
 }
 
 impl App {
   const HELP_HORIZONTAL_PADDING: usize = 8;
   const HELP_KEY_COLUMN_WIDTH: usize = 18;
   const HELP_MIN_WIDTH: u16 = 32;
+  const IDLE_AFTER: Duration = Duration::from_secs(5);
 
   const KEY_BINDINGS: &'static [KeyBinding] = &[
     KeyBinding {
@@ -43,10 +60,82 @@ impl App {
       description: "Focus highlighted pane",
       keys: "enter",
     },
+    KeyBinding {
+      description: "Find pane by title, path or window name",
+      keys: "ctrl-p",
+    },
+    KeyBinding {
+      description: "Jump to / focus labelled pane",
+      keys: "f / F",
+    },
+    KeyBinding {
+      description: "Jump to / focus bookmarked pane",
+      keys: "1-9 / alt-1-9",
+    },
+    KeyBinding {
+      description: "Bookmark highlighted pane",
+      keys: "m 1-9",
+    },
     KeyBinding {
       description: "Hide highlighted pane",
       keys: "x",
     },
+    KeyBinding {
+      description: "Kill / respawn highlighted pane",
+      keys: "K / R",
+    },
+    KeyBinding {
+      description: "Rename window of highlighted pane",
+      keys: "n",
+    },
+    KeyBinding {
+      description: "Break highlighted pane into its own window",
+      keys: "!",
+    },
+    KeyBinding {
+      description: "Swap highlighted pane with labelled pane",
+      keys: "w",
+    },
+    KeyBinding {
+      description: "Spawn window / split next to highlighted pane",
+      keys: "c / C",
+    },
+    KeyBinding {
+      description: "Toggle recording of highlighted pane",
+      keys: "r",
+    },
+    KeyBinding {
+      description: "Toggle asciicast recording of highlighted pane",
+      keys: "a",
+    },
+    KeyBinding {
+      description: "Toggle list view",
+      keys: "v",
+    },
+    KeyBinding {
+      description: "Toggle preview split",
+      keys: "p",
+    },
+    KeyBinding {
+      description: "Cycle / reverse list sort column",
+      keys: "s / S",
+    },
+    KeyBinding {
+      description: "Toggle session tree sidebar",
+      keys: "b",
+    },
+    KeyBinding {
+      description: "Switch focus between sidebar and tiles",
+      keys: "tab",
+    },
+    KeyBinding {
+      description: "Browse history of highlighted pane",
+      keys: "t",
+    },
+    KeyBinding {
+      description: "Step through history",
+      keys: "← / → (history)",
+    },
     KeyBinding {
       description: "Quit spymux",
       keys: "q / esc",
@@ -56,11 +145,21 @@ impl App {
       keys: "?",
     },
     KeyBinding {
-      description: "Select clicked pane",
+      description: "Select clicked pane or tree node",
       keys: "left click",
     },
   ];
 
+  const PREVIEW_LIST_WIDTH: u16 = 40;
+
+  fn bookmark_slot(code: KeyCode) -> Option<u8> {
+    let KeyCode::Char(ch @ '1'..='9') = code else {
+      return None;
+    };
+
+    ch.to_digit(10).and_then(|digit| u8::try_from(digit).ok())
+  }
+
   fn centered_rect(width: u16, height: u16, area: Rect) -> Rect {
     let (width, height) = (width.min(area.width), height.min(area.height));
 
@@ -247,28 +346,70 @@ impl App {
   }
 
   fn ensure_selection(&mut self) {
-    self.selected_pane = if self.tmux.panes.is_empty() {
-      None
-    } else {
-      self
-        .selected_pane
-        .as_ref()
-        .and_then(|current| {
-          self.tmux.panes.iter().find(|pane| pane.id == current.id)
-        })
-        .cloned()
-        .or_else(|| self.tmux.panes.first().cloned())
-    }
+    let panes = self.sidebar.filter(&self.tmux.panes);
+
+    self.selected_pane = self
+      .selected_pane
+      .as_ref()
+      .and_then(|current| panes.iter().find(|pane| pane.id == current.id))
+      .or_else(|| panes.first())
+      .map(|pane| (*pane).clone());
   }
 
   fn focus_pane(&mut self, pane: &Pane) -> Result {
-    Tmux::focus_pane(pane)?;
+    if !pane.is_virtual {
+      Tmux::focus_pane(pane)?;
+
+      if pane.server.is_current() {
+        Tmux::switch_client(pane)?;
+      }
+    }
+
     self.selected_pane = Some(pane.clone());
     Ok(())
   }
 
   fn handle_event(&mut self, event: Event) -> Result<Option<Action>> {
+    if matches!(event, Event::Key(key) if key.kind == KeyEventKind::Press) {
+      self.error = None;
+    }
+
     match event {
+      Event::Key(key)
+        if key.kind == KeyEventKind::Press && self.picker.is_some() =>
+      {
+        return Ok(self.handle_picker_key(key));
+      }
+      Event::Key(key)
+        if key.kind == KeyEventKind::Press && self.jump_labels.is_some() =>
+      {
+        return self.handle_jump_key(key.code);
+      }
+      Event::Key(key)
+        if key.kind == KeyEventKind::Press && self.prompt.is_some() =>
+      {
+        self.handle_prompt_key(key.code)?;
+      }
+      Event::Key(key) if key.kind == KeyEventKind::Press && self.marking => {
+        self.marking = false;
+
+        if let (Some(slot), Some(pane)) =
+          (Self::bookmark_slot(key.code), self.selected_pane())
+        {
+          let result = self.bookmarks.assign(slot, &pane);
+          self.report(result);
+        }
+      }
+      Event::Key(key)
+        if key.kind == KeyEventKind::Press && self.history_view.is_some() =>
+      {
+        self.handle_history_key(key.code);
+      }
+      Event::Key(key)
+        if key.kind == KeyEventKind::Press && self.sidebar.focused =>
+      {
+        return Ok(self.handle_sidebar_key(key.code));
+      }
       Event::Key(key) if key.kind == KeyEventKind::Press => match key.code {
         KeyCode::Char('q') | KeyCode::Esc => {
           return Ok(Some(Action::Quit));
@@ -276,6 +417,88 @@ impl App {
         KeyCode::Char('?') => {
           self.help_visible = !self.help_visible;
         }
+        KeyCode::Char('a') => {
+          self.toggle_cast()?;
+        }
+        KeyCode::Char('b') => {
+          self.sidebar.toggle();
+          self.ensure_selection();
+        }
+        KeyCode::Char('f') => {
+          self.start_jump(JumpMode::Select);
+        }
+        KeyCode::Char('F') => {
+          self.start_jump(JumpMode::Focus);
+        }
+        KeyCode::Char('w') => {
+          self.start_jump(JumpMode::Swap);
+        }
+        KeyCode::Char('K') => {
+          self.start_prompt(PaneAction::Kill);
+        }
+        KeyCode::Char('R') => {
+          self.start_prompt(PaneAction::Respawn);
+        }
+        KeyCode::Char('n') => {
+          self.start_prompt(PaneAction::Rename);
+        }
+        KeyCode::Char('c') => {
+          self.start_prompt(PaneAction::Spawn);
+        }
+        KeyCode::Char('C') => {
+          self.start_prompt(PaneAction::Split);
+        }
+        KeyCode::Char('!') => {
+          if let Some(pane) = self.selected_pane()
+            && !pane.is_virtual
+          {
+            self.report(Tmux::break_pane(&pane));
+            self.refresh_tmux()?;
+          }
+        }
+        KeyCode::Char('m') => {
+          self.marking = true;
+        }
+        KeyCode::Char('1'..='9') => {
+          if let Some(slot) = Self::bookmark_slot(key.code) {
+            return Ok(self.jump_to_bookmark(
+              slot,
+              key.modifiers.contains(KeyModifiers::ALT),
+            ));
+          }
+        }
+        KeyCode::Char('p') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+          self.picker = Some(Picker::default());
+        }
+        KeyCode::Char('p') => {
+          self.view = if self.view == View::Preview {
+            View::Grid
+          } else {
+            View::Preview
+          };
+        }
+        KeyCode::Char('r') => {
+          self.toggle_recording()?;
+        }
+        KeyCode::Char('s') => {
+          self.list_view.cycle_sort();
+        }
+        KeyCode::Char('S') => {
+          self.list_view.reverse = !self.list_view.reverse;
+        }
+        KeyCode::Char('v') => {
+          self.view = if self.view == View::List {
+            View::Grid
+          } else {
+            View::List
+          };
+        }
+        KeyCode::Char('t') => {
+          self.history_view = self
+            .selected_pane
+            .as_ref()
+            .map(|pane| HistoryView::new(pane.id.clone()));
+        }
         KeyCode::Char('x') => {
           self.hide_selected_pane();
         }
@@ -291,9 +514,12 @@ impl App {
         KeyCode::Char('l') | KeyCode::Right => {
           self.move_selection(Movement::Right)?;
         }
+        KeyCode::Tab => {
+          self.sidebar.focused = self.sidebar.visible;
+        }
         KeyCode::Enter => {
           if let Some(pane) = self.selected_pane() {
-            return Ok(Some(Action::FocusPane(pane)));
+            return Ok(Some(Action::FocusPane(Box::new(pane))));
           }
         }
         _ => {}
@@ -307,32 +533,206 @@ impl App {
     Ok(None)
   }
 
+  fn handle_history_key(&mut self, code: KeyCode) {
+    let Some(view) = self.history_view.as_mut() else {
+      return;
+    };
+
+    let len = self
+      .history
+      .snapshots(&view.pane_id)
+      .map_or(0, VecDeque::len);
+
+    match code {
+      KeyCode::Char('q' | 't') | KeyCode::Esc => self.history_view = None,
+      KeyCode::Char('?') => self.help_visible = !self.help_visible,
+      KeyCode::Char('h') | KeyCode::Left => view.step_back(len),
+      KeyCode::Char('l') | KeyCode::Right => view.step_forward(len),
+      KeyCode::Char('g') | KeyCode::Home => view.oldest(),
+      KeyCode::Char('G') | KeyCode::End => view.index = None,
+      _ => {}
+    }
+  }
+
+  fn handle_jump_key(&mut self, code: KeyCode) -> Result<Option<Action>> {
+    let Some(labels) = self.jump_labels.as_mut() else {
+      return Ok(None);
+    };
+
+    let KeyCode::Char(ch) = code else {
+      self.jump_labels = None;
+      return Ok(None);
+    };
+
+    let pane_id = match labels.push(ch) {
+      JumpResult::Pending => return Ok(None),
+      JumpResult::Cancelled => {
+        self.jump_labels = None;
+        return Ok(None);
+      }
+      JumpResult::Selected(pane_id) => pane_id,
+    };
+
+    let (mode, source) = (labels.mode, self.selected_pane());
+
+    self.jump_labels = None;
+    self.select_pane_id(&pane_id);
+
+    match mode {
+      JumpMode::Focus => Ok(
+        self
+          .selected_pane()
+          .map(|pane| Action::FocusPane(Box::new(pane))),
+      ),
+      JumpMode::Select => Ok(None),
+      JumpMode::Swap => {
+        if let (Some(source), Some(target)) = (source, self.selected_pane())
+          && source.id != target.id
+          && !source.is_virtual
+          && !target.is_virtual
+        {
+          let result = Tmux::swap_panes(&source, &target);
+
+          if result.is_ok() {
+            self.selected_pane = Some(source);
+          }
+
+          self.report(result);
+          self.refresh_tmux()?;
+        }
+
+        Ok(None)
+      }
+    }
+  }
+
   fn handle_mouse_event(&mut self, mouse_event: MouseEvent) -> Result {
     if mouse_event.kind != MouseEventKind::Down(MouseButton::Left) {
       return Ok(());
     }
 
-    let Some(pane_index) =
-      self
-        .pane_regions
-        .iter()
-        .enumerate()
-        .find_map(|(index, rect)| {
-          if Self::rect_contains(*rect, mouse_event.column, mouse_event.row) {
-            Some(index)
-          } else {
-            None
-          }
-        })
-    else {
+    if let Some(node) = self.sidebar_regions.iter().find_map(|(node, rect)| {
+      Self::rect_contains(*rect, mouse_event.column, mouse_event.row)
+        .then(|| node.clone())
+    }) {
+      self.sidebar.focused = true;
+      self.sidebar.selected = node;
+      self.ensure_selection();
+      return Ok(());
+    }
+
+    let Some(pane_id) = self.pane_regions.iter().find_map(|(pane_id, rect)| {
+      if Self::rect_contains(*rect, mouse_event.column, mouse_event.row) {
+        Some(pane_id.clone())
+      } else {
+        None
+      }
+    }) else {
+      return Ok(());
+    };
+
+    self.sidebar.focused = false;
+    self.select_pane_id(&pane_id);
+
+    Ok(())
+  }
+
+  fn handle_picker_key(&mut self, key: KeyEvent) -> Option<Action> {
+    let panes = self.sidebar.filter(&self.tmux.panes);
+
+    let picker = self.picker.as_mut()?;
+
+    match key.code {
+      KeyCode::Esc => self.picker = None,
+      KeyCode::Enter => {
+        if let Some(pane) = picker.selection(&panes) {
+          self.selected_pane = Some(pane.clone());
+        }
+
+        self.picker = None;
+      }
+      KeyCode::Char('f') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+        let pane = picker.selection(&panes).cloned();
+
+        self.picker = None;
+
+        return pane.map(|pane| Action::FocusPane(Box::new(pane)));
+      }
+      _ => picker.handle_key(key, &panes),
+    }
+
+    None
+  }
+
+  fn handle_prompt_key(&mut self, code: KeyCode) -> Result {
+    let Some(prompt) = self.prompt.as_mut() else {
       return Ok(());
     };
 
-    self.select_pane_at_index(pane_index);
+    match code {
+      KeyCode::Char(ch) if prompt.is_text_input() => {
+        prompt.focused_mut().push(ch);
+      }
+      KeyCode::Backspace if prompt.is_text_input() => {
+        prompt.focused_mut().pop();
+      }
+      KeyCode::Down | KeyCode::Up if prompt.is_text_input() => {
+        prompt.focus(code == KeyCode::Down);
+      }
+      KeyCode::Tab
+        if matches!(prompt.action, PaneAction::Spawn | PaneAction::Split)
+          && prompt.field == PromptField::Command =>
+      {
+        prompt.cycle_template(self.config.templates.keys());
+      }
+      KeyCode::Enter if prompt.is_text_input() => {
+        if let Some(prompt) = self.prompt.take() {
+          let result = self.perform(prompt);
+          self.report(result);
+        }
+      }
+      KeyCode::Char('y') => {
+        if let Some(prompt) = self.prompt.take() {
+          let result = self.perform(prompt);
+          self.report(result);
+        }
+      }
+      _ => self.prompt = None,
+    }
 
     Ok(())
   }
 
+  fn handle_sidebar_key(&mut self, code: KeyCode) -> Option<Action> {
+    let entries = Sidebar::entries(&self.tmux.panes, &self.tracker);
+
+    match code {
+      KeyCode::Char('q') => return Some(Action::Quit),
+      KeyCode::Char('?') => self.help_visible = !self.help_visible,
+      KeyCode::Char('b') => self.sidebar.toggle(),
+      KeyCode::Enter | KeyCode::Esc | KeyCode::Tab => {
+        self.sidebar.focused = false;
+      }
+      KeyCode::Char('h') | KeyCode::Left => {
+        self.sidebar.move_selection(&entries, Movement::Left);
+      }
+      KeyCode::Char('j') | KeyCode::Down => {
+        self.sidebar.move_selection(&entries, Movement::Down);
+      }
+      KeyCode::Char('k') | KeyCode::Up => {
+        self.sidebar.move_selection(&entries, Movement::Up);
+      }
+      KeyCode::Char('l') | KeyCode::Right => {
+        self.sidebar.move_selection(&entries, Movement::Right);
+      }
+      _ => {}
+    }
+
+    self.ensure_selection();
+
+    None
+  }
+
   fn help_area(area: Rect, line_count: usize, max_line_width: usize) -> Rect {
     let content_width =
       max_line_width.saturating_add(Self::HELP_HORIZONTAL_PADDING);
@@ -378,6 +778,18 @@ impl App {
     self.ensure_selection();
   }
 
+  fn jump_to_bookmark(&mut self, slot: u8, focus: bool) -> Option<Action> {
+    let pane = self.bookmarks.resolve(slot, &self.tmux.panes)?.clone();
+
+    if !self.sidebar.filter(&self.tmux.panes).contains(&&pane) {
+      self.sidebar.selected = SidebarNode::All;
+    }
+
+    self.selected_pane = Some(pane.clone());
+
+    focus.then_some(Action::FocusPane(Box::new(pane)))
+  }
+
   fn line_is_empty(line: &Line<'_>) -> bool {
     if line.spans.is_empty() {
       return true;
@@ -393,30 +805,59 @@ impl App {
 
     self.ensure_selection();
 
-    if self.pane_regions.len() != self.tmux.panes.len() {
-      return Ok(());
-    }
-
     let Some(selected) = self.selected_pane.as_ref() else {
       return Ok(());
     };
 
+    if matches!(self.view, View::List | View::Preview) {
+      let sorted = self.list_view.sorted(
+        &self.sidebar.filter(&self.tmux.panes),
+        &self.tracker,
+        Instant::now(),
+      );
+
+      let Some(current_index) =
+        sorted.iter().position(|pane| pane.id == selected.id)
+      else {
+        return Ok(());
+      };
+
+      let next_index = match direction {
+        Movement::Down => current_index.saturating_add(1),
+        Movement::Up => current_index.saturating_sub(1),
+        Movement::Left | Movement::Right => current_index,
+      };
+
+      if let Some(pane) = sorted.get(next_index) {
+        self.selected_pane = Some((*pane).clone());
+      }
+
+      return Ok(());
+    }
+
     let Some(current_index) = self
-      .tmux
-      .panes
+      .pane_regions
       .iter()
-      .position(|pane| pane.id == selected.id)
+      .position(|(pane_id, _)| *pane_id == selected.id)
     else {
       return Ok(());
     };
 
+    let rects = self
+      .pane_regions
+      .iter()
+      .map(|(_, rect)| *rect)
+      .collect::<Vec<Rect>>();
+
     let Some(next_index) =
-      Self::pane_in_direction(&self.pane_regions, current_index, direction)
+      Self::pane_in_direction(&rects, current_index, direction)
     else {
       return Ok(());
     };
 
-    self.select_pane_at_index(next_index);
+    let pane_id = self.pane_regions[next_index].0.clone();
+
+    self.select_pane_id(&pane_id);
 
     Ok(())
   }
@@ -424,21 +865,65 @@ impl App {
   pub(crate) fn new(config: Config) -> Result<Self> {
     let terminal = TerminalGuard::new()?;
 
-    let mut tmux = Tmux::new(config);
+    let mut tmux = Tmux::new(config.clone());
 
-    if let Ok(pane_id) = env::var("TMUX_PANE") {
+    if let Some(pane_id) = Server::current_pane_id(&config.servers) {
       tmux.exclude_pane_id(&pane_id);
     }
 
     tmux.capture()?;
 
+    let mut history = PaneHistory::new(config.history_budget);
+
+    history.record(&tmux.panes, Local::now());
+
+    let mut tracker = PaneTracker::new(Self::IDLE_AFTER, Vec::new());
+
+    tracker.update(&tmux.panes, Instant::now());
+
+    let mut recorder =
+      Recorder::new(config.record_directory.clone(), config.record_all);
+
+    recorder.record(&tmux.panes, Local::now())?;
+
+    let bookmarks_path = config.data_directory.join("bookmarks.json");
+
+    let (bookmarks, error) = match Bookmarks::load(bookmarks_path.clone()) {
+      Ok(bookmarks) => (
+        bookmarks,
+        (!tmux.failures.is_empty()).then(|| tmux.failures.join("; ")),
+      ),
+      Err(error) => (
+        Bookmarks::new(bookmarks_path),
+        Some(format!("{error:#}, starting with no bookmarks")),
+      ),
+    };
+
     Ok(Self {
+      bookmarks,
+      casts: BTreeMap::new(),
+      highlighter: config
+        .highlight_changes
+        .map(|duration| ChangeHighlighter::new(duration, config.color_output)),
       config,
+      error,
+      history,
+      history_view: None,
+      jump_labels: None,
       last_refresh: Instant::now(),
+      list_view: ListView::default(),
+      marking: false,
       pane_regions: Vec::new(),
+      picker: None,
+      prompt: None,
+      recorder,
       selected_pane: tmux.panes.first().cloned(),
+      sidebar: Sidebar::default(),
+      sidebar_regions: Vec::new(),
       terminal,
       tmux,
+      tracker,
+      view: View::Grid,
       help_visible: false,
     })
   }
@@ -507,6 +992,76 @@ impl App {
     best.map(|(_, _, index)| index)
   }
 
+  fn perform(&mut self, prompt: Prompt) -> Result {
+    let Prompt {
+      action,
+      directory,
+      input,
+      pane,
+      session,
+      ..
+    } = prompt;
+
+    let command = self.config.templates.get(&input).unwrap_or(&input).clone();
+
+    let target = match action {
+      PaneAction::Kill => {
+        Tmux::kill_pane(&pane)?;
+        None
+      }
+      PaneAction::Rename => {
+        Tmux::rename_window(&pane, &input)?;
+        None
+      }
+      PaneAction::Respawn => {
+        Tmux::respawn_pane(&pane)?;
+        None
+      }
+      PaneAction::Spawn => Some(SpawnTarget::Window {
+        name: None,
+        session: Some(if session.trim().is_empty() {
+          pane.session.clone()
+        } else {
+          session.trim().to_string()
+        }),
+      }),
+      PaneAction::Split => Some(SpawnTarget::Split {
+        pane_id: pane.target().to_string(),
+        vertical: false,
+      }),
+    };
+
+    let spawned = target
+      .map(|target| {
+        let directory = Workspace::join(
+          Path::new(&pane.path),
+          Some(Path::new(directory.trim())),
+        );
+
+        let directory = fs::canonicalize(&directory).with_context(|| {
+          format!("failed to resolve directory {}", directory.display())
+        })?;
+
+        Tmux::spawn(
+          &pane.server,
+          &SpawnRequest {
+            command,
+            directory: directory.to_string_lossy().to_string(),
+            target,
+          },
+        )
+      })
+      .transpose()?;
+
+    self.refresh_tmux()?;
+
+    if let Some(pane_id) = spawned {
+      self.select_pane_id(&pane.server.qualify(&pane_id));
+    }
+
+    Ok(())
+  }
+
   fn plain_text(mut text: Text<'static>) -> Text<'static> {
     text.style = Style::default();
 
@@ -530,11 +1085,181 @@ impl App {
 
   fn refresh_tmux(&mut self) -> Result {
     self.tmux.capture()?;
+
+    if self.error.is_none() && !self.tmux.failures.is_empty() {
+      self.error = Some(self.tmux.failures.join("; "));
+    }
+    self.recorder.record(&self.tmux.panes, Local::now())?;
+    self.history.record(&self.tmux.panes, Local::now());
+    self.tracker.update(&self.tmux.panes, Instant::now());
+
+    if let Some(highlighter) = self.highlighter.as_mut() {
+      highlighter.retain(&self.tmux.panes);
+    }
+
+    if self
+      .history_view
+      .as_ref()
+      .is_some_and(|view| self.history.snapshots(&view.pane_id).is_none())
+    {
+      self.history_view = None;
+    }
+
+    let panes = &self.tmux.panes;
+
+    self
+      .casts
+      .retain(|pane_id, _| panes.iter().any(|pane| &pane.id == pane_id));
+
+    for pane in panes {
+      if let Some(cast) = self.casts.get_mut(&pane.id) {
+        cast.record(pane, Instant::now())?;
+      }
+    }
+
+    self
+      .sidebar
+      .retain(&Sidebar::entries(&self.tmux.panes, &self.tracker));
+
     self.ensure_selection();
     self.last_refresh = Instant::now();
     Ok(())
   }
 
+  fn render_error(frame: &mut Frame, area: Rect, error: &str) {
+    let area = Rect::new(
+      area.x,
+      area.y.saturating_add(area.height.saturating_sub(1)),
+      area.width,
+      area.height.min(1),
+    );
+
+    frame.render_widget(Clear, area);
+
+    frame.render_widget(
+      Paragraph::new(format!("error: {error}"))
+        .style(Style::default().fg(Color::White).bg(Color::Red)),
+      area,
+    );
+  }
+
+  fn render_history(
+    frame: &mut Frame,
+    area: Rect,
+    pane: &Pane,
+    snapshots: &VecDeque<Snapshot>,
+    view: &HistoryView,
+    color_output: bool,
+  ) {
+    let [content_area, slider_area] = Layout::default()
+      .direction(Direction::Vertical)
+      .constraints([Constraint::Min(3), Constraint::Length(3)])
+      .areas(area);
+
+    let position = view.position(snapshots.len());
+
+    let content = snapshots
+      .get(position)
+      .map(|snapshot| snapshot.content.as_str())
+      .unwrap_or_default();
+
+    let body = Self::clip_to_bottom(
+      content,
+      usize::from(content_area.height.saturating_sub(2)),
+      usize::from(content_area.width.saturating_sub(2)),
+      color_output,
+    );
+
+    frame.render_widget(
+      Paragraph::new(body).wrap(Wrap { trim: false }).block(
+        Block::default()
+          .title(format!("{} (history)", pane.title()))
+          .borders(Borders::ALL)
+          .border_type(BorderType::Rounded)
+          .border_style(Style::default().fg(Color::Cyan)),
+      ),
+      content_area,
+    );
+
+    let label = format!(
+      "{}/{} {}{}",
+      position + 1,
+      snapshots.len(),
+      snapshots
+        .get(position)
+        .map(|snapshot| snapshot
+          .captured_at
+          .format("%Y-%m-%d %H:%M:%S")
+          .to_string())
+        .unwrap_or_default(),
+      if view.index.is_none() { " (live)" } else { "" }
+    );
+
+    let ratio = if snapshots.len() <= 1 {
+      1.0
+    } else {
+      f64::from(u32::try_from(position).unwrap_or(u32::MAX))
+        / f64::from(u32::try_from(snapshots.len() - 1).unwrap_or(u32::MAX))
+    };
+
+    frame.render_widget(
+      LineGauge::default()
+        .block(
+          Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded),
+        )
+        .filled_style(Style::default().fg(Color::Cyan))
+        .label(label)
+        .ratio(ratio),
+      slider_area,
+    );
+  }
+
+  fn render_tile(
+    frame: &mut Frame,
+    area: Rect,
+    pane: &Pane,
+    title: Line<'static>,
+    is_selected: bool,
+    highlighter: Option<&mut ChangeHighlighter>,
+    color_output: bool,
+  ) {
+    let visible_columns = usize::from(area.width.saturating_sub(2));
+
+    let mut content = Self::clip_to_bottom(
+      &pane.content,
+      usize::from(area.height.saturating_sub(2)),
+      visible_columns,
+      color_output,
+    );
+
+    if let Some(highlighter) = highlighter {
+      highlighter.apply(
+        &pane.id,
+        &mut content,
+        visible_columns,
+        Instant::now(),
+      );
+    }
+
+    let mut block = Block::default()
+      .title(title)
+      .borders(Borders::ALL)
+      .border_type(BorderType::Rounded);
+
+    if is_selected {
+      block = block.border_style(Style::default().fg(Color::Cyan));
+    }
+
+    frame.render_widget(
+      Paragraph::new(content)
+        .wrap(Wrap { trim: false })
+        .block(block),
+      area,
+    );
+  }
+
   fn renderable_line_count(text: &Text<'static>) -> usize {
     let mut end = text.lines.len();
 
@@ -545,6 +1270,12 @@ impl App {
     end
   }
 
+  fn report(&mut self, result: Result) {
+    if let Err(error) = result {
+      self.error = Some(format!("{error:#}"));
+    }
+  }
+
   pub(crate) fn run(mut self) -> Result {
     loop {
       self.tick()?;
@@ -561,8 +1292,16 @@ impl App {
         if let Some(action) = self.handle_event(event)? {
           match action {
             Action::Quit => break,
+            Action::FocusPane(pane)
+              if !pane.is_virtual && env::var_os("TMUX").is_none() =>
+            {
+              Tmux::focus_pane(&pane)?;
+              drop(self);
+              return Tmux::attach(&pane);
+            }
             Action::FocusPane(pane) => {
-              self.focus_pane(&pane)?;
+              let result = self.focus_pane(&pane);
+              self.report(result);
             }
           }
         }
@@ -572,8 +1311,8 @@ impl App {
     Ok(())
   }
 
-  fn select_pane_at_index(&mut self, pane_index: usize) {
-    if let Some(pane) = self.tmux.panes.get(pane_index) {
+  fn select_pane_id(&mut self, pane_id: &str) {
+    if let Some(pane) = self.tmux.panes.iter().find(|pane| pane.id == pane_id) {
       self.selected_pane = Some(pane.clone());
     }
   }
@@ -636,6 +1375,22 @@ impl App {
     }
   }
 
+  fn start_jump(&mut self, mode: JumpMode) {
+    self.jump_labels = (!self.pane_regions.is_empty()).then(|| {
+      JumpLabels::new(
+        self.pane_regions.iter().map(|(id, _)| id.clone()).collect(),
+        mode,
+      )
+    });
+  }
+
+  fn start_prompt(&mut self, action: PaneAction) {
+    self.prompt = self
+      .selected_pane()
+      .filter(|pane| !pane.is_virtual)
+      .map(|pane| Prompt::new(action, pane));
+  }
+
   fn tick(&mut self) -> Result {
     if self.last_refresh.elapsed() >= self.config.refresh_rate {
       self.refresh_tmux()?;
@@ -644,9 +1399,47 @@ impl App {
     let terminal = self.terminal.terminal_mut();
 
     terminal.draw(|frame| {
-      let body_area = frame.area();
+      let (sidebar_area, body_area) = self.sidebar.split(frame.area());
+
+      self.sidebar_regions = sidebar_area.map_or_else(Vec::new, |area| {
+        self.sidebar.render(
+          frame,
+          area,
+          &Sidebar::entries(&self.tmux.panes, &self.tracker),
+        )
+      });
+
+      let now = Instant::now();
+
+      let panes = self.sidebar.filter(&self.tmux.panes);
+
+      let selected_id =
+        self.selected_pane.as_ref().map(|pane| pane.id.as_str());
+
+      let history = self.history_view.as_ref().and_then(|view| {
+        Some((
+          view,
+          self
+            .tmux
+            .panes
+            .iter()
+            .find(|pane| pane.id == view.pane_id)?,
+          self.history.snapshots(&view.pane_id)?,
+        ))
+      });
+
+      if let Some((view, pane, snapshots)) = history {
+        self.pane_regions.clear();
 
-      if self.tmux.panes.is_empty() {
+        Self::render_history(
+          frame,
+          body_area,
+          pane,
+          snapshots,
+          view,
+          self.config.color_output,
+        );
+      } else if self.tmux.panes.is_empty() {
         self.pane_regions.clear();
 
         let widget = Paragraph::new("No tmux panes detected").block(
@@ -657,50 +1450,118 @@ impl App {
         );
 
         frame.render_widget(widget, body_area);
-      } else {
-        let pane_count = self.tmux.panes.len();
-        let pane_areas = Self::compute_pane_regions(body_area, pane_count);
-
-        self.pane_regions.clone_from(&pane_areas);
-
-        for (pane_index, pane) in self.tmux.panes.iter().enumerate() {
-          let pane_area = pane_areas[pane_index];
-
-          let (inner_height, inner_width) = (
-            pane_area.height.saturating_sub(2),
-            pane_area.width.saturating_sub(2),
+      } else if self.view == View::List {
+        let sorted = self.list_view.sorted(&panes, &self.tracker, now);
+
+        self.pane_regions = self.list_view.render(
+          frame,
+          body_area,
+          &sorted,
+          selected_id,
+          &self.tracker,
+          now,
+        );
+      } else if self.view == View::Preview {
+        let sorted = self.list_view.sorted(&panes, &self.tracker, now);
+
+        let [list_area, preview_area] = Layout::default()
+          .direction(Direction::Horizontal)
+          .constraints([
+            Constraint::Length(
+              Self::PREVIEW_LIST_WIDTH.min(body_area.width / 3),
+            ),
+            Constraint::Fill(1),
+          ])
+          .areas(body_area);
+
+        self.pane_regions =
+          ListView::render_compact(frame, list_area, &sorted, selected_id);
+
+        if let Some(pane) = sorted
+          .iter()
+          .find(|pane| selected_id == Some(pane.id.as_str()))
+        {
+          Self::render_tile(
+            frame,
+            preview_area,
+            pane,
+            Self::title_line(
+              pane,
+              &self
+                .config
+                .title
+                .render(pane, self.tracker.idle_for(&pane.id, now)),
+              None,
+              self.bookmarks.slot(pane, &self.tmux.panes),
+              &self.recorder,
+              &self.casts,
+              preview_area.width,
+            )
+            .alignment(self.config.title.position.alignment()),
+            false,
+            self.highlighter.as_mut(),
+            self.config.color_output,
           );
-
-          let (visible_lines, visible_columns) =
-            (usize::from(inner_height), usize::from(inner_width));
-
-          let clipped_content = Self::clip_to_bottom(
-            &pane.content,
-            visible_lines,
-            visible_columns,
+        }
+      } else {
+        let pane_areas = Self::compute_pane_regions(body_area, panes.len());
+
+        self.pane_regions = panes
+          .iter()
+          .zip(&pane_areas)
+          .map(|(pane, area)| (pane.id.clone(), *area))
+          .collect();
+
+        for (pane, pane_area) in panes.iter().zip(pane_areas) {
+          Self::render_tile(
+            frame,
+            pane_area,
+            pane,
+            Self::title_line(
+              pane,
+              &self
+                .config
+                .title
+                .render(pane, self.tracker.idle_for(&pane.id, now)),
+              self
+                .jump_labels
+                .as_ref()
+                .and_then(|labels| labels.label(&pane.id)),
+              self.bookmarks.slot(pane, &self.tmux.panes),
+              &self.recorder,
+              &self.casts,
+              pane_area.width,
+            )
+            .alignment(self.config.title.position.alignment()),
+            selected_id == Some(pane.id.as_str()),
+            self.highlighter.as_mut(),
             self.config.color_output,
           );
+        }
+      }
 
-          let mut block = Block::default()
-            .title(pane.title())
-            .borders(Borders::ALL)
-            .border_type(BorderType::Rounded);
-
-          let is_selected = self
-            .selected_pane
-            .as_ref()
-            .is_some_and(|selected| selected.id == pane.id);
-
-          if is_selected {
-            block = block.border_style(Style::default().fg(Color::Cyan));
-          }
+      if let Some(labels) = self.jump_labels.as_ref()
+        && self.view != View::Grid
+      {
+        labels.render_overlay(frame, &self.pane_regions);
+      }
 
-          let widget = Paragraph::new(clipped_content)
-            .wrap(Wrap { trim: false })
-            .block(block);
+      if let Some(prompt) = self.prompt.as_ref() {
+        prompt.render(frame, body_area);
+      } else if let Some(error) = self.error.as_deref() {
+        Self::render_error(frame, body_area, error);
+      }
 
-          frame.render_widget(widget, pane_area);
-        }
+      if let Some(picker) = self.picker.as_ref() {
+        picker.render(
+          frame,
+          Self::centered_rect(
+            body_area.width.saturating_mul(3) / 4,
+            body_area.height.saturating_mul(2) / 3,
+            body_area,
+          ),
+          &panes,
+        );
       }
 
       if self.help_visible && body_area.width > 0 && body_area.height > 0 {
@@ -727,6 +1588,91 @@ impl App {
 
     Ok(())
   }
+
+  fn title_line(
+    pane: &Pane,
+    title: &str,
+    label: Option<&str>,
+    slot: Option<u8>,
+    recorder: &Recorder,
+    casts: &BTreeMap<String, CastRecorder<File>>,
+    width: u16,
+  ) -> Line<'static> {
+    let mut spans = Vec::new();
+
+    if let Some(label) = label {
+      spans.push(JumpLabels::span(label));
+      spans.push(Span::raw(" "));
+    }
+
+    if let Some(slot) = slot {
+      spans.push(Span::styled(
+        format!("[{slot}] "),
+        Style::default().fg(Color::Yellow),
+      ));
+    }
+
+    if pane.is_virtual {
+      spans.push(Span::styled("◆ ", Style::default().fg(Color::Cyan)));
+    }
+
+    let mut markers = Vec::new();
+
+    if recorder.is_recording(&pane.id) {
+      markers.push(Span::styled(" ● rec", Style::default().fg(Color::Red)));
+    }
+
+    if casts.contains_key(&pane.id) {
+      markers
+        .push(Span::styled(" ● cast", Style::default().fg(Color::Magenta)));
+    }
+
+    let available = usize::from(width.saturating_sub(2)).saturating_sub(
+      spans.iter().chain(&markers).map(Span::width).sum::<usize>(),
+    );
+
+    spans.push(Span::raw(TitleFormat::truncate(title, available)));
+    spans.extend(markers);
+
+    Line::from(spans)
+  }
+
+  fn toggle_cast(&mut self) -> Result {
+    let Some(pane) = self.selected_pane() else {
+      return Ok(());
+    };
+
+    if self.casts.remove(&pane.id).is_some() {
+      return Ok(());
+    }
+
+    fs::create_dir_all(&self.config.record_directory).with_context(|| {
+      format!(
+        "failed to create directory {}",
+        self.config.record_directory.display()
+      )
+    })?;
+
+    let path = self.config.record_directory.join(format!(
+      "{}-{}.cast",
+      pane.file_stem(),
+      Local::now().format("%Y%m%d-%H%M%S")
+    ));
+
+    self
+      .casts
+      .insert(pane.id.clone(), CastRecorder::create(&path, &pane)?);
+
+    Ok(())
+  }
+
+  fn toggle_recording(&mut self) -> Result {
+    if let Some(pane) = self.selected_pane() {
+      self.recorder.toggle(&pane, Local::now())?;
+    }
+
+    Ok(())
+  }
 }
 
 #[cfg(test)]
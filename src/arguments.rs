@@ -24,19 +24,81 @@ pub(crate) struct Arguments {
 
 impl Arguments {
   pub(crate) fn run(self) -> Result {
+    let default = Config::default();
+
+    let config_file = ConfigFile::load(self.options.config.as_deref())?;
+
+    let servers = self
+      .options
+      .socket_name
+      .into_iter()
+      .map(Server::Name)
+      .chain(self.options.socket_path.into_iter().map(Server::Path))
+      .collect::<Vec<Server>>();
+
+    let idle_shells = if self.options.show_idle_shells {
+      Vec::new()
+    } else {
+      config_file.idle_shells()
+    };
+
+    let mut excluded_commands = config_file.exclude_commands;
+
+    if !self.options.show_spymux {
+      excluded_commands.extend(default.excluded_commands);
+    }
+
+    let config = Config {
+      color_output: !self.options.no_colors,
+      data_directory: default.data_directory,
+      excluded_commands,
+      filters: self.options.filter,
+      highlight_changes: self
+        .options
+        .highlight_changes
+        .map(|duration| Duration::from_millis(duration.get())),
+      history_budget: self
+        .options
+        .history_budget
+        .map_or(default.history_budget, |budget| budget.saturating_mul(1024)),
+      idle_shells,
+      record_all: self.options.record,
+      record_directory: self
+        .options
+        .record_dir
+        .unwrap_or(default.record_directory),
+      refresh_rate: self
+        .options
+        .refresh_rate
+        .map_or(default.refresh_rate, |rate| {
+          Duration::from_millis(rate.get())
+        }),
+      servers: if servers.is_empty() {
+        default.servers
+      } else {
+        servers
+      },
+      sort: self.options.sort,
+      templates: config_file.templates,
+      title: config_file.title,
+      virtual_panes: config_file.virtual_panes,
+      workspace_windows: None,
+    };
+
     if let Some(subcommand) = self.subcommand {
-      subcommand.run()
+      subcommand.run(config)
     } else {
-      let refresh_rate = self.options.refresh_rate.map_or_else(
-        || Config::default().refresh_rate,
-        |rate| Duration::from_millis(rate.get()),
-      );
-
-      App::new(Config {
-        color_output: !self.options.no_colors,
-        refresh_rate,
-      })?
-      .run()
+      App::new(config)?.run()
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use {super::*, clap::CommandFactory};
+
+  #[test]
+  fn arguments_are_well_formed() {
+    Arguments::command().debug_assert();
+  }
+}
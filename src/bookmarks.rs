@@ -0,0 +1,223 @@
+use super::*;
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+struct Bookmark {
+  index: usize,
+  pane_id: String,
+  #[serde(default)]
+  server: Server,
+  session: String,
+  window_index: usize,
+}
+
+impl Bookmark {
+  fn matches_location(&self, pane: &Pane) -> bool {
+    !pane.is_virtual
+      && pane.server == self.server
+      && pane.session == self.session
+      && pane.window_index == self.window_index
+      && pane.index == self.index
+  }
+
+  fn new(pane: &Pane) -> Self {
+    Self {
+      index: pane.index,
+      pane_id: pane.id.clone(),
+      server: pane.server.clone(),
+      session: pane.session.clone(),
+      window_index: pane.window_index,
+    }
+  }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct Bookmarks {
+  path: PathBuf,
+  slots: BTreeMap<u8, Bookmark>,
+}
+
+impl Bookmarks {
+  pub(crate) fn assign(&mut self, slot: u8, pane: &Pane) -> Result {
+    self.slots.retain(|_, bookmark| bookmark.pane_id != pane.id);
+    self.slots.insert(slot, Bookmark::new(pane));
+    self.save()
+  }
+
+  pub(crate) fn load(path: PathBuf) -> Result<Self> {
+    let slots = match fs::read_to_string(&path) {
+      Ok(contents) => serde_json::from_str(&contents).with_context(|| {
+        format!("failed to parse bookmarks in {}", path.display())
+      })?,
+      Err(error) if error.kind() == io::ErrorKind::NotFound => {
+        return Ok(Self::new(path));
+      }
+      Err(error) => {
+        return Err(error).with_context(|| {
+          format!("failed to read bookmarks from {}", path.display())
+        });
+      }
+    };
+
+    Ok(Self { path, slots })
+  }
+
+  pub(crate) fn new(path: PathBuf) -> Self {
+    Self {
+      path,
+      slots: BTreeMap::new(),
+    }
+  }
+
+  pub(crate) fn resolve<'a>(
+    &self,
+    slot: u8,
+    panes: &'a [Pane],
+  ) -> Option<&'a Pane> {
+    let bookmark = self.slots.get(&slot)?;
+
+    panes
+      .iter()
+      .find(|pane| pane.id == bookmark.pane_id)
+      .or_else(|| panes.iter().find(|pane| bookmark.matches_location(pane)))
+  }
+
+  fn save(&self) -> Result {
+    if let Some(parent) = self.path.parent() {
+      fs::create_dir_all(parent).with_context(|| {
+        format!("failed to create directory {}", parent.display())
+      })?;
+    }
+
+    fs::write(&self.path, serde_json::to_string_pretty(&self.slots)?)
+      .with_context(|| {
+        format!("failed to write bookmarks to {}", self.path.display())
+      })
+  }
+
+  pub(crate) fn slot(&self, pane: &Pane, panes: &[Pane]) -> Option<u8> {
+    self.slots.keys().copied().find(|slot| {
+      self
+        .resolve(*slot, panes)
+        .is_some_and(|resolved| resolved.id == pane.id)
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn path(name: &str) -> PathBuf {
+    let directory = env::temp_dir()
+      .join(format!("spymux-bookmarks-{}-{name}", process::id()));
+
+    fs::remove_dir_all(&directory).ok();
+
+    directory.join("bookmarks.json")
+  }
+
+  fn pane(id: &str, session: &str, window_index: usize) -> Pane {
+    Pane {
+      id: id.into(),
+      session: session.into(),
+      window_index,
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn assign_persists_and_reloads() {
+    let path = path("persist");
+
+    let mut bookmarks = Bookmarks::load(path.clone()).unwrap();
+
+    bookmarks.assign(3, &pane("%4", "work", 1)).unwrap();
+
+    let panes = [pane("%1", "work", 0), pane("%4", "work", 1)];
+
+    let reloaded = Bookmarks::load(path.clone()).unwrap();
+
+    assert_eq!(reloaded.resolve(3, &panes).unwrap().id, "%4");
+    assert_eq!(reloaded.slot(&panes[1], &panes), Some(3));
+    assert_eq!(reloaded.slot(&panes[0], &panes), None);
+
+    fs::remove_dir_all(path.parent().unwrap()).unwrap();
+  }
+
+  #[test]
+  fn assign_moves_pane_between_slots() {
+    let path = path("move");
+
+    let mut bookmarks = Bookmarks::load(path.clone()).unwrap();
+
+    let panes = [pane("%4", "work", 1)];
+
+    bookmarks.assign(1, &panes[0]).unwrap();
+    bookmarks.assign(2, &panes[0]).unwrap();
+
+    assert!(bookmarks.resolve(1, &panes).is_none());
+    assert_eq!(bookmarks.slot(&panes[0], &panes), Some(2));
+
+    fs::remove_dir_all(path.parent().unwrap()).unwrap();
+  }
+
+  #[test]
+  fn resolve_falls_back_to_location_when_id_is_gone() {
+    let path = path("fallback");
+
+    let mut bookmarks = Bookmarks::load(path.clone()).unwrap();
+
+    bookmarks.assign(1, &pane("%4", "work", 1)).unwrap();
+
+    let panes = [pane("%9", "work", 1), pane("%10", "play", 1)];
+
+    assert_eq!(bookmarks.resolve(1, &panes).unwrap().id, "%9");
+
+    fs::remove_dir_all(path.parent().unwrap()).unwrap();
+  }
+
+  #[test]
+  fn resolve_falls_back_to_location_on_the_same_server() {
+    let path = path("server");
+
+    let mut bookmarks = Bookmarks::load(path.clone()).unwrap();
+
+    let work = Server::Name("work".into());
+
+    bookmarks
+      .assign(
+        1,
+        &Pane {
+          server: work.clone(),
+          ..pane("work/%3", "api", 1)
+        },
+      )
+      .unwrap();
+
+    let panes = [
+      pane("%3", "api", 1),
+      Pane {
+        server: work,
+        ..pane("work/%8", "api", 1)
+      },
+    ];
+
+    let reloaded = Bookmarks::load(path.clone()).unwrap();
+
+    assert_eq!(reloaded.resolve(1, &panes).unwrap().id, "work/%8");
+
+    fs::remove_dir_all(path.parent().unwrap()).unwrap();
+  }
+
+  #[test]
+  fn load_rejects_malformed_file() {
+    let path = path("malformed");
+
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, "not json").unwrap();
+
+    assert!(Bookmarks::load(path.clone()).is_err());
+
+    fs::remove_dir_all(path.parent().unwrap()).unwrap();
+  }
+}
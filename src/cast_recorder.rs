@@ -0,0 +1,218 @@
+use super::*;
+
+#[derive(Debug)]
+pub(crate) struct CastRecorder<W: Write> {
+  content: String,
+  height: u16,
+  started: Instant,
+  width: u16,
+  writer: W,
+}
+
+impl CastRecorder<File> {
+  pub(crate) fn create(path: &Path, pane: &Pane) -> Result<Self> {
+    let file = File::create(path)
+      .with_context(|| format!("failed to create {}", path.display()))?;
+
+    Self::new(file, pane, SystemTime::now(), Instant::now())
+  }
+}
+
+impl<W: Write> CastRecorder<W> {
+  fn event(&mut self, now: Instant, kind: &str, data: &str) -> Result {
+    let elapsed = now.saturating_duration_since(self.started).as_secs_f64();
+
+    writeln!(
+      self.writer,
+      "{}",
+      serde_json::to_string(&(
+        (elapsed * 1_000_000.0).round() / 1_000_000.0,
+        kind,
+        data
+      ))?
+    )?;
+
+    self.writer.flush()?;
+
+    Ok(())
+  }
+
+  fn frame(content: &str, height: u16) -> String {
+    let mut frame = String::from("\x1b[H");
+
+    for (index, line) in content.lines().take(usize::from(height)).enumerate() {
+      if index > 0 {
+        frame.push_str("\r\n");
+      }
+
+      frame.push_str(line);
+      frame.push_str("\x1b[0m\x1b[K");
+    }
+
+    frame.push_str("\x1b[J");
+
+    frame
+  }
+
+  pub(crate) fn new(
+    mut writer: W,
+    pane: &Pane,
+    timestamp: SystemTime,
+    started: Instant,
+  ) -> Result<Self> {
+    let header = serde_json::json!({
+      "height": pane.height,
+      "timestamp": timestamp
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default(),
+      "title": pane.title(),
+      "version": 2,
+      "width": pane.width,
+    });
+
+    writeln!(writer, "{header}")?;
+
+    let mut recorder = Self {
+      content: String::new(),
+      height: pane.height,
+      started,
+      width: pane.width,
+      writer,
+    };
+
+    recorder.record(pane, started)?;
+
+    Ok(recorder)
+  }
+
+  pub(crate) fn record(&mut self, pane: &Pane, now: Instant) -> Result {
+    let resized = pane.width != self.width || pane.height != self.height;
+
+    if resized {
+      self.width = pane.width;
+      self.height = pane.height;
+      self.event(now, "r", &format!("{}x{}", pane.width, pane.height))?;
+    }
+
+    if !resized && pane.content == self.content {
+      return Ok(());
+    }
+
+    self.content.clone_from(&pane.content);
+
+    self.event(now, "o", &Self::frame(&pane.content, self.height))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn pane(content: &str, width: u16, height: u16) -> Pane {
+    Pane {
+      command: "codex".into(),
+      content: content.into(),
+      height,
+      session: "work".into(),
+      width,
+      ..Default::default()
+    }
+  }
+
+  fn lines(recorder: &CastRecorder<Vec<u8>>) -> Vec<serde_json::Value> {
+    String::from_utf8(recorder.writer.clone())
+      .unwrap()
+      .lines()
+      .map(|line| serde_json::from_str(line).unwrap())
+      .collect()
+  }
+
+  #[test]
+  fn header_uses_pane_dimensions() {
+    let recorder = CastRecorder::new(
+      Vec::new(),
+      &pane("", 120, 40),
+      UNIX_EPOCH + Duration::from_secs(42),
+      Instant::now(),
+    )
+    .unwrap();
+
+    assert_eq!(
+      lines(&recorder)[0],
+      serde_json::json!({
+        "height": 40,
+        "timestamp": 42,
+        "title": "work:0.0 (codex)",
+        "version": 2,
+        "width": 120,
+      })
+    );
+  }
+
+  #[test]
+  fn unchanged_content_is_not_recorded_again() {
+    let started = Instant::now();
+
+    let mut recorder = CastRecorder::new(
+      Vec::new(),
+      &pane("one\ntwo\n", 80, 24),
+      UNIX_EPOCH,
+      started,
+    )
+    .unwrap();
+
+    recorder
+      .record(
+        &pane("one\ntwo\n", 80, 24),
+        started + Duration::from_secs(1),
+      )
+      .unwrap();
+
+    recorder
+      .record(
+        &pane("one\ntwo\nthree\n", 80, 24),
+        started + Duration::from_millis(1500),
+      )
+      .unwrap();
+
+    let lines = lines(&recorder);
+
+    assert_eq!(lines.len(), 3);
+
+    assert_eq!(
+      lines[1],
+      serde_json::json!([
+        0.0,
+        "o",
+        "\x1b[Hone\x1b[0m\x1b[K\r\ntwo\x1b[0m\x1b[K\x1b[J"
+      ])
+    );
+
+    assert_eq!(lines[2][0], serde_json::json!(1.5));
+  }
+
+  #[test]
+  fn resize_is_recorded_before_redraw() {
+    let started = Instant::now();
+
+    let mut recorder =
+      CastRecorder::new(Vec::new(), &pane("one", 80, 24), UNIX_EPOCH, started)
+        .unwrap();
+
+    recorder.record(&pane("one", 100, 2), started).unwrap();
+
+    let lines = lines(&recorder);
+
+    assert_eq!(lines[2], serde_json::json!([0.0, "r", "100x2"]));
+    assert_eq!(lines[3][1], serde_json::json!("o"));
+  }
+
+  #[test]
+  fn frame_is_clipped_to_pane_height() {
+    assert_eq!(
+      CastRecorder::<Vec<u8>>::frame("a\nb\nc\n", 2),
+      "\x1b[Ha\x1b[0m\x1b[K\r\nb\x1b[0m\x1b[K\x1b[J"
+    );
+  }
+}
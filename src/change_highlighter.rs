@@ -0,0 +1,282 @@
+use super::*;
+
+#[derive(Debug)]
+struct HighlightState {
+  changed_at: Vec<Option<Instant>>,
+  lines: Vec<String>,
+  width: usize,
+}
+
+#[derive(Debug)]
+pub(crate) struct ChangeHighlighter {
+  color_output: bool,
+  duration: Duration,
+  panes: BTreeMap<String, HighlightState>,
+}
+
+impl ChangeHighlighter {
+  const HIGHLIGHT: (u8, u8, u8) = (96, 86, 24);
+
+  pub(crate) fn apply(
+    &mut self,
+    pane_id: &str,
+    text: &mut Text<'static>,
+    width: usize,
+    now: Instant,
+  ) {
+    let lines = text
+      .lines
+      .iter()
+      .map(|line| {
+        line
+          .spans
+          .iter()
+          .map(|span| span.content.as_ref())
+          .collect::<String>()
+      })
+      .collect::<Vec<String>>();
+
+    let state = match self.panes.get_mut(pane_id) {
+      Some(state) if state.width == width => state,
+      _ => {
+        self.panes.insert(
+          pane_id.to_string(),
+          HighlightState {
+            changed_at: vec![None; lines.len()],
+            lines,
+            width,
+          },
+        );
+
+        return;
+      }
+    };
+
+    if state.lines != lines {
+      state.changed_at =
+        Self::changed_at(&state.lines, &state.changed_at, &lines, now);
+      state.lines = lines;
+    }
+
+    for (line, changed_at) in text.lines.iter_mut().zip(&state.changed_at) {
+      let Some(changed_at) = changed_at else {
+        continue;
+      };
+
+      let elapsed = now.saturating_duration_since(*changed_at);
+
+      if elapsed >= self.duration {
+        continue;
+      }
+
+      line.style = if self.color_output {
+        line.style.bg(Self::fade(elapsed, self.duration))
+      } else {
+        line.style.add_modifier(Modifier::REVERSED)
+      };
+    }
+  }
+
+  fn changed_at(
+    previous: &[String],
+    previous_changed_at: &[Option<Instant>],
+    current: &[String],
+    now: Instant,
+  ) -> Vec<Option<Instant>> {
+    let (matched, start) = line_diff::appended_from(previous, current);
+
+    if start > 0 {
+      return previous_changed_at[matched - start..matched]
+        .iter()
+        .copied()
+        .chain(iter::repeat_n(Some(now), current.len() - start))
+        .collect();
+    }
+
+    current
+      .iter()
+      .enumerate()
+      .map(|(index, line)| {
+        if previous.get(index) == Some(line) {
+          previous_changed_at.get(index).copied().flatten()
+        } else {
+          Some(now)
+        }
+      })
+      .collect()
+  }
+
+  fn fade(elapsed: Duration, duration: Duration) -> Color {
+    let (total, remaining) = (
+      duration.as_millis().max(1),
+      duration.saturating_sub(elapsed).as_millis(),
+    );
+
+    let scale = |channel: u8| {
+      u8::try_from(u128::from(channel) * remaining / total).unwrap_or(u8::MAX)
+    };
+
+    let (red, green, blue) = Self::HIGHLIGHT;
+
+    Color::Rgb(scale(red), scale(green), scale(blue))
+  }
+
+  pub(crate) fn new(duration: Duration, color_output: bool) -> Self {
+    Self {
+      color_output,
+      duration,
+      panes: BTreeMap::new(),
+    }
+  }
+
+  pub(crate) fn retain(&mut self, panes: &[Pane]) {
+    self
+      .panes
+      .retain(|pane_id, _| panes.iter().any(|pane| &pane.id == pane_id));
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn highlighted(text: &Text<'static>) -> Vec<bool> {
+    text
+      .lines
+      .iter()
+      .map(|line| line.style.bg.is_some())
+      .collect()
+  }
+
+  #[test]
+  fn first_render_is_not_highlighted() {
+    let mut highlighter = ChangeHighlighter::new(Duration::from_secs(2), true);
+
+    let mut text = Text::raw("one\ntwo");
+
+    highlighter.apply("%0", &mut text, 80, Instant::now());
+
+    assert_eq!(highlighted(&text), vec![false, false]);
+  }
+
+  #[test]
+  fn scrolled_lines_keep_their_state_and_new_lines_are_highlighted() {
+    let mut highlighter = ChangeHighlighter::new(Duration::from_secs(2), true);
+
+    let now = Instant::now();
+
+    highlighter.apply("%0", &mut Text::raw("one\ntwo\nthree"), 80, now);
+
+    let mut text = Text::raw("two\nthree\nfour");
+
+    highlighter.apply("%0", &mut text, 80, now);
+
+    assert_eq!(highlighted(&text), vec![false, false, true]);
+  }
+
+  #[test]
+  fn rewritten_lines_are_highlighted_in_place() {
+    let mut highlighter = ChangeHighlighter::new(Duration::from_secs(2), true);
+
+    let now = Instant::now();
+
+    highlighter.apply("%0", &mut Text::raw("a\nb\nc"), 80, now);
+
+    let mut text = Text::raw("a\nB\nc");
+
+    highlighter.apply("%0", &mut text, 80, now);
+
+    assert_eq!(highlighted(&text), vec![false, true, false]);
+  }
+
+  #[test]
+  fn rewritten_last_line_keeps_earlier_timestamps() {
+    let start = Instant::now();
+
+    let (first, second, now) = (
+      start,
+      start + Duration::from_secs(1),
+      start + Duration::from_secs(2),
+    );
+
+    assert_eq!(
+      ChangeHighlighter::changed_at(
+        &["a".to_string(), "b 10%".to_string()],
+        &[Some(first), Some(second)],
+        &["a".to_string(), "b 20%".to_string()],
+        now,
+      ),
+      vec![Some(first), Some(now)]
+    );
+  }
+
+  #[test]
+  fn highlight_expires_after_duration() {
+    let mut highlighter = ChangeHighlighter::new(Duration::from_secs(2), true);
+
+    let now = Instant::now();
+
+    highlighter.apply("%0", &mut Text::raw("a"), 80, now);
+    highlighter.apply("%0", &mut Text::raw("b"), 80, now);
+
+    let mut text = Text::raw("b");
+
+    highlighter.apply("%0", &mut text, 80, now + Duration::from_secs(1));
+    assert_eq!(highlighted(&text), vec![true]);
+
+    let mut text = Text::raw("b");
+
+    highlighter.apply("%0", &mut text, 80, now + Duration::from_secs(3));
+    assert_eq!(highlighted(&text), vec![false]);
+  }
+
+  #[test]
+  fn span_styles_are_preserved() {
+    let mut highlighter = ChangeHighlighter::new(Duration::from_secs(2), true);
+
+    let now = Instant::now();
+
+    highlighter.apply("%0", &mut Text::raw("a"), 80, now);
+
+    let mut text = Text::from(Line::from(Span::styled(
+      "b",
+      Style::default().fg(Color::Red),
+    )));
+
+    highlighter.apply("%0", &mut text, 80, now);
+
+    assert_eq!(
+      text.lines[0].spans[0].style,
+      Style::default().fg(Color::Red)
+    );
+    assert!(text.lines[0].style.bg.is_some());
+  }
+
+  #[test]
+  fn resized_tiles_are_not_highlighted() {
+    let mut highlighter = ChangeHighlighter::new(Duration::from_secs(2), true);
+
+    let now = Instant::now();
+
+    highlighter.apply("%0", &mut Text::raw("a"), 80, now);
+
+    let mut text = Text::raw("b");
+
+    highlighter.apply("%0", &mut text, 40, now);
+
+    assert_eq!(highlighted(&text), vec![false]);
+  }
+
+  #[test]
+  fn fade_dims_towards_black() {
+    assert_eq!(
+      ChangeHighlighter::fade(Duration::ZERO, Duration::from_secs(2)),
+      Color::Rgb(96, 86, 24)
+    );
+
+    assert_eq!(
+      ChangeHighlighter::fade(Duration::from_secs(1), Duration::from_secs(2)),
+      Color::Rgb(48, 43, 12)
+    );
+  }
+}
@@ -1,16 +1,51 @@
 use super::*;
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub(crate) struct Config {
   pub(crate) color_output: bool,
+  pub(crate) data_directory: PathBuf,
+  pub(crate) excluded_commands: Vec<String>,
+  pub(crate) filters: Vec<PaneFilter>,
+  pub(crate) highlight_changes: Option<Duration>,
+  pub(crate) history_budget: usize,
+  pub(crate) idle_shells: Vec<String>,
+  pub(crate) record_all: bool,
+  pub(crate) record_directory: PathBuf,
   pub(crate) refresh_rate: Duration,
+  pub(crate) servers: Vec<Server>,
+  pub(crate) sort: Option<PaneSort>,
+  pub(crate) templates: BTreeMap<String, String>,
+  pub(crate) title: TitleFormat,
+  pub(crate) virtual_panes: Vec<VirtualPane>,
+  pub(crate) workspace_windows: Option<BTreeSet<(String, String)>>,
 }
 
 impl Default for Config {
   fn default() -> Self {
     Self {
       color_output: true,
+      data_directory: env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .or_else(|| {
+          env::var_os("HOME")
+            .map(|home| PathBuf::from(home).join(".local/share"))
+        })
+        .unwrap_or_else(env::temp_dir)
+        .join(env!("CARGO_PKG_NAME")),
+      excluded_commands: vec![env!("CARGO_PKG_NAME").to_string()],
+      filters: Vec::new(),
+      highlight_changes: None,
+      history_budget: 1024 * 1024,
+      idle_shells: Vec::new(),
+      record_all: false,
+      record_directory: PathBuf::from("spymux-logs"),
       refresh_rate: Duration::from_millis(500),
+      servers: vec![Server::Default],
+      sort: None,
+      templates: BTreeMap::new(),
+      title: TitleFormat::default(),
+      virtual_panes: Vec::new(),
+      workspace_windows: None,
     }
   }
 }
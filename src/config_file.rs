@@ -0,0 +1,135 @@
+use super::*;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct ConfigFile {
+  pub(crate) exclude_commands: Vec<String>,
+  idle_shells: Option<Vec<String>>,
+  pub(crate) templates: BTreeMap<String, String>,
+  pub(crate) title: TitleFormat,
+  pub(crate) virtual_panes: Vec<VirtualPane>,
+}
+
+impl ConfigFile {
+  const DEFAULT_IDLE_SHELLS: &'static [&'static str] =
+    &["bash", "dash", "fish", "ksh", "nu", "sh", "tcsh", "zsh"];
+
+  fn default_path() -> Option<PathBuf> {
+    Self::directory().map(|directory| directory.join("config.toml"))
+  }
+
+  pub(crate) fn directory() -> Option<PathBuf> {
+    env::var_os("XDG_CONFIG_HOME")
+      .map(PathBuf::from)
+      .or_else(|| {
+        env::var_os("HOME").map(|home| PathBuf::from(home).join(".config"))
+      })
+      .map(|directory| directory.join(env!("CARGO_PKG_NAME")))
+  }
+
+  pub(crate) fn idle_shells(&self) -> Vec<String> {
+    self.idle_shells.clone().unwrap_or_else(|| {
+      Self::DEFAULT_IDLE_SHELLS
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+    })
+  }
+
+  pub(crate) fn load(path: Option<&Path>) -> Result<Self> {
+    match path {
+      Some(path) => Self::read(path),
+      None => match Self::default_path() {
+        Some(path) if path.is_file() => Self::read(&path),
+        _ => Ok(Self::default()),
+      },
+    }
+  }
+
+  fn read(path: &Path) -> Result<Self> {
+    let contents = fs::read_to_string(path).with_context(|| {
+      format!("failed to read config file {}", path.display())
+    })?;
+
+    let config: Self = toml::from_str(&contents).with_context(|| {
+      format!("failed to parse config file {}", path.display())
+    })?;
+
+    for pane in &config.virtual_panes {
+      pane
+        .validate()
+        .with_context(|| format!("invalid config file {}", path.display()))?;
+    }
+
+    Ok(config)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn path(name: &str, contents: &str) -> PathBuf {
+    let path = env::temp_dir()
+      .join(format!("spymux-config-{}-{name}.toml", process::id()));
+
+    fs::write(&path, contents).unwrap();
+
+    path
+  }
+
+  #[test]
+  fn load_reads_templates() {
+    let path = path(
+      "templates",
+      "[templates]\ncodex = \"codex --full-auto\"\nshell = \"bash -l\"\n",
+    );
+
+    let config = ConfigFile::load(Some(&path)).unwrap();
+
+    assert_eq!(
+      config.templates.get("codex").map(String::as_str),
+      Some("codex --full-auto")
+    );
+
+    assert_eq!(config.templates.len(), 2);
+
+    fs::remove_file(path).unwrap();
+  }
+
+  #[test]
+  fn idle_shells_default_to_common_shells() {
+    let shells = ConfigFile::default().idle_shells();
+
+    assert!(shells.contains(&"bash".to_string()));
+    assert!(shells.contains(&"zsh".to_string()));
+  }
+
+  #[test]
+  fn load_overrides_idle_shells() {
+    let path = path("idle-shells", "idle_shells = [\"xonsh\"]\n");
+
+    assert_eq!(
+      ConfigFile::load(Some(&path)).unwrap().idle_shells(),
+      ["xonsh"]
+    );
+
+    fs::remove_file(path).unwrap();
+  }
+
+  #[test]
+  fn load_rejects_unknown_keys() {
+    let path = path("unknown", "colour = true\n");
+
+    assert!(ConfigFile::load(Some(&path)).is_err());
+
+    fs::remove_file(path).unwrap();
+  }
+
+  #[test]
+  fn load_requires_explicit_path_to_exist() {
+    assert!(
+      ConfigFile::load(Some(Path::new("/nonexistent/spymux.toml"))).is_err()
+    );
+  }
+}
@@ -0,0 +1,87 @@
+const BOUNDARY_BONUS: i64 = 3;
+const CONSECUTIVE_BONUS: i64 = 5;
+const MAX_GAP_PENALTY: usize = 10;
+
+fn eq_ignore_case(a: char, b: char) -> bool {
+  a.to_lowercase().eq(b.to_lowercase())
+}
+
+pub(crate) fn score(query: &str, candidate: &str) -> Option<i64> {
+  let (query, candidate) = (
+    query
+      .chars()
+      .filter(|ch| !ch.is_whitespace())
+      .collect::<Vec<char>>(),
+    candidate.chars().collect::<Vec<char>>(),
+  );
+
+  let Some(first) = query.first() else {
+    return Some(0);
+  };
+
+  (0..candidate.len())
+    .filter(|start| eq_ignore_case(candidate[*start], *first))
+    .filter_map(|start| score_from(&query, &candidate, start))
+    .max()
+}
+
+fn score_from(query: &[char], candidate: &[char], start: usize) -> Option<i64> {
+  let (mut score, mut position, mut previous) = (0i64, start, None);
+
+  for needle in query {
+    let index = position
+      + candidate[position..]
+        .iter()
+        .position(|ch| eq_ignore_case(*ch, *needle))?;
+
+    let gap = previous.map_or(index, |previous: usize| index - previous - 1);
+
+    score = score
+      .saturating_add(1)
+      .saturating_sub(i64::try_from(gap.min(MAX_GAP_PENALTY)).unwrap_or(0));
+
+    if gap == 0 && previous.is_some() {
+      score = score.saturating_add(CONSECUTIVE_BONUS);
+    }
+
+    if index == 0 || !candidate[index - 1].is_alphanumeric() {
+      score = score.saturating_add(BOUNDARY_BONUS);
+    }
+
+    previous = Some(index);
+    position = index + 1;
+  }
+
+  Some(score)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn empty_query_matches_everything() {
+    assert_eq!(score("", "anything"), Some(0));
+  }
+
+  #[test]
+  fn query_must_be_a_subsequence() {
+    assert!(score("wrk", "work:1.0").is_some());
+    assert!(score("krw", "work:1.0").is_none());
+  }
+
+  #[test]
+  fn matching_ignores_case_and_whitespace() {
+    assert!(score("Api Srv", "projects/api-server").is_some());
+  }
+
+  #[test]
+  fn consecutive_and_boundary_matches_score_higher() {
+    let (contiguous, scattered) = (
+      score("codex", "work:0.1 (codex)").unwrap(),
+      score("codex", "cargo doc --examples").unwrap(),
+    );
+
+    assert!(contiguous > scattered);
+  }
+}
@@ -0,0 +1,71 @@
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) struct HistoryView {
+  pub(crate) index: Option<usize>,
+  pub(crate) pane_id: String,
+}
+
+impl HistoryView {
+  pub(crate) fn new(pane_id: String) -> Self {
+    Self {
+      index: None,
+      pane_id,
+    }
+  }
+
+  pub(crate) fn oldest(&mut self) {
+    self.index = Some(0);
+  }
+
+  pub(crate) fn position(&self, len: usize) -> usize {
+    let latest = len.saturating_sub(1);
+    self.index.map_or(latest, |index| index.min(latest))
+  }
+
+  pub(crate) fn step_back(&mut self, len: usize) {
+    self.index = Some(self.position(len).saturating_sub(1));
+  }
+
+  pub(crate) fn step_forward(&mut self, len: usize) {
+    let next = self.position(len).saturating_add(1);
+
+    self.index = if next + 1 >= len { None } else { Some(next) };
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn new_view_follows_latest_snapshot() {
+    let view = HistoryView::new("%0".into());
+
+    assert_eq!(view.position(5), 4);
+    assert_eq!(view.position(7), 6);
+  }
+
+  #[test]
+  fn stepping_back_stops_at_oldest_snapshot() {
+    let mut view = HistoryView::new("%0".into());
+
+    view.step_back(3);
+    assert_eq!(view.position(3), 1);
+
+    view.step_back(3);
+    view.step_back(3);
+    assert_eq!(view.position(3), 0);
+  }
+
+  #[test]
+  fn stepping_forward_to_latest_resumes_following() {
+    let mut view = HistoryView::new("%0".into());
+
+    view.oldest();
+    view.step_forward(3);
+    assert_eq!(view.index, Some(1));
+
+    view.step_forward(3);
+    assert_eq!(view.index, None);
+    assert_eq!(view.position(4), 3);
+  }
+}
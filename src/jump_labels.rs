@@ -0,0 +1,156 @@
+use super::*;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum JumpMode {
+  Focus,
+  Select,
+  Swap,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum JumpResult {
+  Cancelled,
+  Pending,
+  Selected(String),
+}
+
+#[derive(Debug)]
+pub(crate) struct JumpLabels {
+  labels: Vec<(String, String)>,
+  pub(crate) mode: JumpMode,
+  typed: String,
+}
+
+impl JumpLabels {
+  const ALPHABET: &'static str = "asdfghjklqwertyuiopzxcvbnm";
+
+  pub(crate) fn label(&self, pane_id: &str) -> Option<&str> {
+    self
+      .labels
+      .iter()
+      .find(|(label, id)| id == pane_id && label.starts_with(&self.typed))
+      .map(|(label, _)| label.as_str())
+  }
+
+  fn labels(count: usize) -> Vec<String> {
+    let alphabet = Self::ALPHABET.chars().collect::<Vec<char>>();
+
+    if count <= alphabet.len() {
+      return alphabet.iter().take(count).map(char::to_string).collect();
+    }
+
+    alphabet
+      .iter()
+      .flat_map(|first| {
+        alphabet
+          .iter()
+          .map(move |second| format!("{first}{second}"))
+      })
+      .take(count)
+      .collect()
+  }
+
+  pub(crate) fn new(pane_ids: Vec<String>, mode: JumpMode) -> Self {
+    Self {
+      labels: Self::labels(pane_ids.len())
+        .into_iter()
+        .zip(pane_ids)
+        .collect(),
+      mode,
+      typed: String::new(),
+    }
+  }
+
+  pub(crate) fn push(&mut self, ch: char) -> JumpResult {
+    self.typed.push(ch);
+
+    if let Some((_, pane_id)) =
+      self.labels.iter().find(|(label, _)| *label == self.typed)
+    {
+      return JumpResult::Selected(pane_id.clone());
+    }
+
+    if self
+      .labels
+      .iter()
+      .any(|(label, _)| label.starts_with(&self.typed))
+    {
+      JumpResult::Pending
+    } else {
+      JumpResult::Cancelled
+    }
+  }
+
+  pub(crate) fn render_overlay(
+    &self,
+    frame: &mut Frame,
+    regions: &[(String, Rect)],
+  ) {
+    for (pane_id, rect) in regions {
+      let Some(label) = self.label(pane_id) else {
+        continue;
+      };
+
+      let width = u16::try_from(label.len()).unwrap_or(u16::MAX);
+
+      let area = Rect::new(rect.x, rect.y, width.min(rect.width), 1);
+
+      frame.render_widget(Paragraph::new(Self::span(label)), area);
+    }
+  }
+
+  pub(crate) fn span(label: &str) -> Span<'static> {
+    Span::styled(
+      label.to_string(),
+      Style::default()
+        .fg(Color::Black)
+        .bg(Color::Yellow)
+        .add_modifier(Modifier::BOLD),
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn ids(count: usize) -> Vec<String> {
+    (0..count).map(|index| format!("%{index}")).collect()
+  }
+
+  #[test]
+  fn few_panes_get_single_letter_labels() {
+    let labels = JumpLabels::new(ids(3), JumpMode::Select);
+
+    assert_eq!(labels.label("%0"), Some("a"));
+    assert_eq!(labels.label("%2"), Some("d"));
+    assert_eq!(labels.label("%3"), None);
+  }
+
+  #[test]
+  fn many_panes_get_unique_two_letter_labels() {
+    let labels = JumpLabels::labels(30);
+
+    assert_eq!(labels.len(), 30);
+    assert!(labels.iter().all(|label| label.len() == 2));
+    assert_eq!(labels[0], "aa");
+    assert_eq!(labels[26], "sa");
+  }
+
+  #[test]
+  fn push_selects_matching_label() {
+    let mut labels = JumpLabels::new(ids(30), JumpMode::Focus);
+
+    assert_eq!(labels.push('s'), JumpResult::Pending);
+    assert_eq!(labels.label("%0"), None);
+    assert_eq!(labels.label("%26"), Some("sa"));
+    assert_eq!(labels.push('a'), JumpResult::Selected("%26".into()));
+  }
+
+  #[test]
+  fn push_cancels_unknown_label() {
+    let mut labels = JumpLabels::new(ids(3), JumpMode::Select);
+
+    assert_eq!(labels.push('z'), JumpResult::Cancelled);
+  }
+}
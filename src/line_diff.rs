@@ -0,0 +1,48 @@
+pub(crate) fn appended_from<T: PartialEq>(
+  previous: &[T],
+  current: &[T],
+) -> (usize, usize) {
+  let overlap = |previous: &[T]| {
+    (1..=previous.len().min(current.len()))
+      .rev()
+      .find(|&length| previous[previous.len() - length..] == current[..length])
+      .map(|length| (previous.len(), length))
+  };
+
+  overlap(previous)
+    .or_else(|| previous.split_last().and_then(|(_, rest)| overlap(rest)))
+    .unwrap_or((previous.len(), 0))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn unchanged_capture_has_no_new_lines() {
+    assert_eq!(appended_from(&["a", "b"], &["a", "b"]), (2, 2));
+  }
+
+  #[test]
+  fn scrolled_capture_yields_appended_lines() {
+    assert_eq!(
+      appended_from(&["a", "b", "c"], &["b", "c", "d", "e"]),
+      (3, 2)
+    );
+  }
+
+  #[test]
+  fn unrelated_capture_is_entirely_new() {
+    assert_eq!(appended_from(&["a", "b"], &["c", "d"]), (2, 0));
+  }
+
+  #[test]
+  fn rewritten_last_line_is_treated_as_new() {
+    assert_eq!(appended_from(&["a", "b 10%"], &["a", "b 20%"]), (1, 1));
+  }
+
+  #[test]
+  fn empty_previous_capture_is_entirely_new() {
+    assert_eq!(appended_from::<&str>(&[], &["a"]), (0, 0));
+  }
+}
@@ -0,0 +1,415 @@
+use super::*;
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) enum ListColumn {
+  Command,
+  Cpu,
+  #[default]
+  Descriptor,
+  Idle,
+  LastLine,
+  Memory,
+  Path,
+  State,
+}
+
+impl ListColumn {
+  const ALL: [Self; 8] = [
+    Self::Descriptor,
+    Self::Command,
+    Self::Path,
+    Self::Idle,
+    Self::State,
+    Self::Cpu,
+    Self::Memory,
+    Self::LastLine,
+  ];
+
+  fn compare(
+    self,
+    a: &Pane,
+    b: &Pane,
+    tracker: &PaneTracker,
+    now: Instant,
+  ) -> Ordering {
+    match self {
+      Self::Descriptor => (&a.server, &a.session, a.window_index, a.index)
+        .cmp(&(&b.server, &b.session, b.window_index, b.index)),
+      Self::Cpu => Self::process_value(a, |process| u64::from(process.cpu))
+        .cmp(&Self::process_value(b, |process| u64::from(process.cpu))),
+      Self::Idle => tracker
+        .idle_for(&a.id, now)
+        .cmp(&tracker.idle_for(&b.id, now)),
+      Self::Memory => Self::process_value(a, |process| process.memory)
+        .cmp(&Self::process_value(b, |process| process.memory)),
+      _ => self
+        .value(a, tracker, now)
+        .cmp(&self.value(b, tracker, now)),
+    }
+  }
+
+  fn constraint(self) -> Constraint {
+    match self {
+      Self::Command | Self::Path => Constraint::Fill(1),
+      Self::Descriptor => Constraint::Length(16),
+      Self::Cpu | Self::Memory | Self::Idle | Self::State => {
+        Constraint::Length(7)
+      }
+      Self::LastLine => Constraint::Fill(2),
+    }
+  }
+
+  fn last_line(pane: &Pane) -> String {
+    pane
+      .plain_lines()
+      .into_iter()
+      .rev()
+      .find(|line| !line.trim().is_empty())
+      .unwrap_or_default()
+  }
+
+  fn next(self) -> Self {
+    let index = Self::ALL
+      .iter()
+      .position(|column| *column == self)
+      .unwrap_or_default();
+
+    Self::ALL[(index + 1) % Self::ALL.len()]
+  }
+
+  fn process_value(pane: &Pane, value: fn(&ProcessInfo) -> u64) -> Option<u64> {
+    pane.process.as_ref().map(value)
+  }
+
+  fn title(self) -> &'static str {
+    match self {
+      Self::Command => "command",
+      Self::Cpu => "cpu",
+      Self::Descriptor => "pane",
+      Self::Idle => "idle",
+      Self::LastLine => "last line",
+      Self::Memory => "mem",
+      Self::Path => "path",
+      Self::State => "state",
+    }
+  }
+
+  fn value(self, pane: &Pane, tracker: &PaneTracker, now: Instant) -> String {
+    match self {
+      Self::Command => pane.field("process").unwrap_or_default(),
+      Self::Cpu => pane
+        .process
+        .as_ref()
+        .map(|process| format!("{}%", process.cpu))
+        .unwrap_or_default(),
+      Self::Descriptor => pane.location(),
+      Self::Idle => ListView::format_duration(tracker.idle_for(&pane.id, now)),
+      Self::LastLine => Self::last_line(pane),
+      Self::Memory => pane
+        .process
+        .as_ref()
+        .map(|process| ListView::format_memory(process.memory))
+        .unwrap_or_default(),
+      Self::Path => pane.path.clone(),
+      Self::State => if pane.dead {
+        "dead"
+      } else if pane.bell {
+        "bell"
+      } else if tracker.is_idle(&pane.id) {
+        "idle"
+      } else {
+        "active"
+      }
+      .to_string(),
+    }
+  }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct ListView {
+  pub(crate) reverse: bool,
+  pub(crate) sort: ListColumn,
+}
+
+impl ListView {
+  pub(crate) fn cycle_sort(&mut self) {
+    self.sort = self.sort.next();
+  }
+
+  pub(crate) fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+
+    match seconds {
+      0..60 => format!("{seconds}s"),
+      60..3600 => format!("{}m", seconds / 60),
+      3600..86400 => format!("{}h", seconds / 3600),
+      _ => format!("{}d", seconds / 86400),
+    }
+  }
+
+  fn format_memory(kibibytes: u64) -> String {
+    match kibibytes {
+      0..1024 => format!("{kibibytes}K"),
+      1024..1_048_576 => format!("{}M", kibibytes / 1024),
+      _ => format!(
+        "{}.{}G",
+        kibibytes / 1_048_576,
+        kibibytes % 1_048_576 * 10 / 1_048_576
+      ),
+    }
+  }
+
+  pub(crate) fn render(
+    &self,
+    frame: &mut Frame,
+    area: Rect,
+    panes: &[&Pane],
+    selected: Option<&str>,
+    tracker: &PaneTracker,
+    now: Instant,
+  ) -> Vec<(String, Rect)> {
+    let header = Row::new(ListColumn::ALL.iter().map(|column| {
+      let mut title = column.title().to_string();
+
+      if *column == self.sort {
+        title.push_str(if self.reverse { " ▲" } else { " ▼" });
+      }
+
+      Cell::from(title)
+    }))
+    .style(Style::default().add_modifier(Modifier::BOLD));
+
+    let rows = panes.iter().map(|pane| {
+      Row::new(
+        ListColumn::ALL
+          .iter()
+          .map(|column| Cell::from(column.value(pane, tracker, now))),
+      )
+    });
+
+    let table = Table::new(rows, ListColumn::ALL.map(ListColumn::constraint))
+      .header(header)
+      .row_highlight_style(Style::default().fg(Color::Black).bg(Color::Cyan))
+      .block(
+        Block::default()
+          .title(env!("CARGO_PKG_NAME"))
+          .borders(Borders::ALL)
+          .border_type(BorderType::Rounded),
+      );
+
+    let mut state = TableState::default().with_selected(
+      panes
+        .iter()
+        .position(|pane| selected.is_some_and(|id| id == pane.id)),
+    );
+
+    frame.render_stateful_widget(table, area, &mut state);
+
+    let (rows_top, rows_bottom) = (
+      area.y.saturating_add(2),
+      area.y.saturating_add(area.height).saturating_sub(1),
+    );
+
+    panes
+      .iter()
+      .skip(state.offset())
+      .zip(rows_top..rows_bottom)
+      .map(|(pane, y)| {
+        (
+          pane.id.clone(),
+          Rect::new(area.x + 1, y, area.width.saturating_sub(2), 1),
+        )
+      })
+      .collect()
+  }
+
+  pub(crate) fn render_compact(
+    frame: &mut Frame,
+    area: Rect,
+    panes: &[&Pane],
+    selected: Option<&str>,
+  ) -> Vec<(String, Rect)> {
+    let list = List::new(panes.iter().map(|pane| pane.title()))
+      .highlight_style(Style::default().fg(Color::Black).bg(Color::Cyan))
+      .block(
+        Block::default()
+          .title(env!("CARGO_PKG_NAME"))
+          .borders(Borders::ALL)
+          .border_type(BorderType::Rounded),
+      );
+
+    let mut state = ListState::default().with_selected(
+      panes
+        .iter()
+        .position(|pane| selected.is_some_and(|id| id == pane.id)),
+    );
+
+    frame.render_stateful_widget(list, area, &mut state);
+
+    let (rows_top, rows_bottom) = (
+      area.y.saturating_add(1),
+      area.y.saturating_add(area.height).saturating_sub(1),
+    );
+
+    panes
+      .iter()
+      .skip(state.offset())
+      .zip(rows_top..rows_bottom)
+      .map(|(pane, y)| {
+        (
+          pane.id.clone(),
+          Rect::new(area.x + 1, y, area.width.saturating_sub(2), 1),
+        )
+      })
+      .collect()
+  }
+
+  pub(crate) fn sorted<'a>(
+    &self,
+    panes: &[&'a Pane],
+    tracker: &PaneTracker,
+    now: Instant,
+  ) -> Vec<&'a Pane> {
+    let mut sorted = panes.to_vec();
+
+    if self.sort == ListColumn::LastLine {
+      if self.reverse {
+        sorted.sort_by_cached_key(|pane| Reverse(ListColumn::last_line(pane)));
+      } else {
+        sorted.sort_by_cached_key(|pane| ListColumn::last_line(pane));
+      }
+
+      return sorted;
+    }
+
+    sorted.sort_by(|a, b| {
+      let ordering = self.sort.compare(a, b, tracker, now);
+
+      if self.reverse {
+        ordering.reverse()
+      } else {
+        ordering
+      }
+    });
+
+    sorted
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn pane(id: &str, session: &str, command: &str, content: &str) -> Pane {
+    Pane {
+      command: command.into(),
+      content: content.into(),
+      id: id.into(),
+      session: session.into(),
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn format_duration_picks_largest_unit() {
+    assert_eq!(ListView::format_duration(Duration::from_secs(42)), "42s");
+    assert_eq!(ListView::format_duration(Duration::from_secs(150)), "2m");
+    assert_eq!(ListView::format_duration(Duration::from_hours(2)), "2h");
+    assert_eq!(ListView::format_duration(Duration::from_hours(48)), "2d");
+  }
+
+  #[test]
+  fn format_memory_picks_largest_unit() {
+    assert_eq!(ListView::format_memory(512), "512K");
+    assert_eq!(ListView::format_memory(204_800), "200M");
+    assert_eq!(ListView::format_memory(1_572_864), "1.5G");
+  }
+
+  #[test]
+  fn sorted_orders_by_selected_column() {
+    let (first, second, third) = (
+      pane("%0", "b", "vim", ""),
+      pane("%1", "a", "zsh", ""),
+      pane("%2", "c", "bash", ""),
+    );
+
+    let panes = [&first, &second, &third];
+
+    let tracker = PaneTracker::new(Duration::from_secs(5), Vec::new());
+
+    let mut view = ListView::default();
+
+    let ids = |view: &ListView| {
+      view
+        .sorted(&panes, &tracker, Instant::now())
+        .iter()
+        .map(|pane| pane.id.clone())
+        .collect::<Vec<String>>()
+    };
+
+    assert_eq!(ids(&view), vec!["%1", "%0", "%2"]);
+
+    view.cycle_sort();
+    assert_eq!(view.sort, ListColumn::Command);
+    assert_eq!(ids(&view), vec!["%2", "%0", "%1"]);
+
+    view.reverse = true;
+    assert_eq!(ids(&view), vec!["%1", "%0", "%2"]);
+  }
+
+  #[test]
+  fn sorted_orders_by_last_non_blank_line() {
+    let (first, second, third) = (
+      pane("%0", "a", "", "\x1b[1mzeta\x1b[0m\n\n"),
+      pane("%1", "b", "", "beta\nalpha\n"),
+      pane("%2", "c", "", "gamma"),
+    );
+
+    let panes = [&first, &second, &third];
+
+    let tracker = PaneTracker::new(Duration::from_secs(5), Vec::new());
+
+    let mut view = ListView {
+      sort: ListColumn::LastLine,
+      ..Default::default()
+    };
+
+    let ids = |view: &ListView| {
+      view
+        .sorted(&panes, &tracker, Instant::now())
+        .iter()
+        .map(|pane| pane.id.clone())
+        .collect::<Vec<String>>()
+    };
+
+    assert_eq!(ids(&view), vec!["%1", "%2", "%0"]);
+
+    view.reverse = true;
+    assert_eq!(ids(&view), vec!["%0", "%2", "%1"]);
+  }
+
+  #[test]
+  fn last_line_skips_trailing_blank_lines() {
+    let tracker = PaneTracker::new(Duration::from_secs(5), Vec::new());
+
+    assert_eq!(
+      ListColumn::LastLine.value(
+        &pane("%0", "a", "", "first\nsecond\n   \n\n"),
+        &tracker,
+        Instant::now()
+      ),
+      "second"
+    );
+  }
+
+  #[test]
+  fn cycle_sort_wraps_around() {
+    let mut column = ListColumn::Descriptor;
+
+    for _ in 0..ListColumn::ALL.len() {
+      column = column.next();
+    }
+
+    assert_eq!(column, ListColumn::Descriptor);
+  }
+}
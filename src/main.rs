@@ -4,44 +4,81 @@ use {
   anyhow::{Context, Error, anyhow, bail},
   app::App,
   arguments::Arguments,
+  bookmarks::Bookmarks,
+  cast_recorder::CastRecorder,
+  change_highlighter::ChangeHighlighter,
+  chrono::{DateTime, Local},
   clap::Parser,
   command_runner::{CommandRunner, TmuxCommandRunner},
   config::Config,
+  config_file::ConfigFile,
   crossterm::{
     event::{
-      self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode,
-      KeyEventKind, MouseButton, MouseEvent, MouseEventKind,
+      self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent,
+      KeyEventKind, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
     },
     execute,
     style::Stylize,
     terminal::{self, EnterAlternateScreen, LeaveAlternateScreen},
   },
+  history_view::HistoryView,
+  jump_labels::{JumpLabels, JumpMode, JumpResult},
+  list_view::ListView,
   movement::Movement,
   options::Options,
   pane::Pane,
+  pane_event::{EventRecord, PaneEvent},
+  pane_filter::PaneFilter,
+  pane_history::{PaneHistory, Snapshot},
+  pane_sort::PaneSort,
+  pane_tracker::PaneTracker,
+  picker::Picker,
+  process_info::{ProcessInfo, ProcessMonitor},
+  prompt::{PaneAction, Prompt, PromptField},
   ratatui::{
-    Terminal,
+    Frame, Terminal,
     backend::CrosstermBackend,
-    layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Style},
-    text::{Line, Text},
-    widgets::{Block, BorderType, Borders, Clear, Paragraph, Wrap},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::{
+      Block, BorderType, Borders, Cell, Clear, LineGauge, List, ListState,
+      Paragraph, Row, Table, TableState, Wrap,
+    },
   },
+  recorder::Recorder,
+  regex::{Regex, RegexBuilder},
   row_cursor::RowCursor,
-  serde::Deserialize,
+  serde::{Deserialize, Deserializer, Serialize},
+  server::Server,
+  sidebar::{Sidebar, SidebarNode},
+  spawn_request::{SpawnRequest, SpawnTarget},
   std::{
     backtrace::BacktraceStatus,
     borrow::Cow,
+    cell::RefCell,
+    cmp::{Ordering, Reverse},
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
     env,
-    io::{self, IsTerminal, Stdout, Write},
+    fs::{self, File, OpenOptions},
+    io::{self, IsTerminal, Read, Seek, SeekFrom, Stdout, Write},
+    iter, mem,
     num::NonZeroU64,
+    path::{Path, PathBuf},
     process::{self, Command, Output, Stdio},
-    time::{Duration, Instant},
+    slice,
+    sync::mpsc::{self, Receiver, TryRecvError},
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
   },
   subcommand::Subcommand,
   terminal_guard::TerminalGuard,
+  title_format::TitleFormat,
   tmux::Tmux,
   unicode_width::UnicodeWidthChar,
+  view::View,
+  virtual_panes::{VirtualPane, VirtualPanes},
+  workspace::{Workspace, WorkspaceSession, WorkspaceWindow},
 };
 
 type Result<T = (), E = Error> = std::result::Result<T, E>;
@@ -49,15 +86,40 @@ type Result<T = (), E = Error> = std::result::Result<T, E>;
 mod action;
 mod app;
 mod arguments;
+mod bookmarks;
+mod cast_recorder;
+mod change_highlighter;
 mod command_runner;
 mod config;
+mod config_file;
+mod fuzzy;
+mod history_view;
+mod jump_labels;
+mod line_diff;
+mod list_view;
 mod movement;
 mod options;
 mod pane;
+mod pane_event;
+mod pane_filter;
+mod pane_history;
+mod pane_sort;
+mod pane_tracker;
+mod picker;
+mod process_info;
+mod prompt;
+mod recorder;
 mod row_cursor;
+mod server;
+mod sidebar;
+mod spawn_request;
 mod subcommand;
 mod terminal_guard;
+mod title_format;
 mod tmux;
+mod view;
+mod virtual_panes;
+mod workspace;
 
 fn main() {
   let arguments = Arguments::parse();
@@ -2,8 +2,43 @@ use super::*;
 
 #[derive(Debug, Clone, Parser)]
 pub(crate) struct Options {
+  #[clap(
+    long,
+    value_name = "FILE",
+    help = "Config file to load (default: ~/.config/spymux/config.toml)"
+  )]
+  pub(crate) config: Option<PathBuf>,
+  #[clap(
+    long,
+    value_name = "FIELD=VALUE",
+    value_parser = PaneFilter::parse,
+    help = "Only show panes whose field matches, with =, != or ~ for a regex \
+            (repeatable)"
+  )]
+  pub(crate) filter: Vec<PaneFilter>,
+  #[clap(
+    long = "highlight-changes",
+    value_name = "MILLISECONDS",
+    value_parser = clap::value_parser!(NonZeroU64),
+    help = "Highlight changed lines, fading out over this many milliseconds"
+  )]
+  pub(crate) highlight_changes: Option<NonZeroU64>,
+  #[clap(
+    long = "history-budget",
+    value_name = "KIBIBYTES",
+    help = "Memory budget for each pane's content history (default: 1024)"
+  )]
+  pub(crate) history_budget: Option<usize>,
   #[clap(short, long, help = "Disable colored output")]
   pub(crate) no_colors: bool,
+  #[clap(long, help = "Record the output of every pane to log files")]
+  pub(crate) record: bool,
+  #[clap(
+    long = "record-dir",
+    value_name = "DIR",
+    help = "Directory to write pane recordings to (default: spymux-logs)"
+  )]
+  pub(crate) record_dir: Option<PathBuf>,
   #[clap(
     long = "refresh-rate",
     value_name = "MILLISECONDS",
@@ -11,4 +46,32 @@ pub(crate) struct Options {
     help = "Refresh interval in milliseconds (default: 500)"
   )]
   pub(crate) refresh_rate: Option<NonZeroU64>,
+  #[clap(
+    long,
+    help = "Show panes whose shell is idle at a prompt instead of hiding them"
+  )]
+  pub(crate) show_idle_shells: bool,
+  #[clap(long, help = "Show other spymux instances instead of hiding them")]
+  pub(crate) show_spymux: bool,
+  #[clap(
+    short = 'L',
+    long = "socket-name",
+    value_name = "NAME",
+    help = "Watch the tmux server with this socket name (repeatable)"
+  )]
+  pub(crate) socket_name: Vec<String>,
+  #[clap(
+    short = 'S',
+    long = "socket-path",
+    value_name = "PATH",
+    help = "Watch the tmux server at this socket path (repeatable)"
+  )]
+  pub(crate) socket_path: Vec<PathBuf>,
+  #[clap(
+    long,
+    value_name = "FIELD[:desc]",
+    value_parser = PaneSort::parse,
+    help = "Order panes by a field (default: tmux order)"
+  )]
+  pub(crate) sort: Option<PaneSort>,
 }
@@ -1,43 +1,297 @@
 use super::*;
 
-#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
 pub(crate) struct Pane {
-  pub(crate) command: String,
   #[serde(default)]
+  pub(crate) active: bool,
+  #[serde(default)]
+  pub(crate) activity: bool,
+  #[serde(default)]
+  pub(crate) bell: bool,
+  pub(crate) command: String,
+  #[serde(default, skip_serializing)]
   pub(crate) content: String,
+  #[serde(default)]
+  pub(crate) dead: bool,
+  #[serde(default)]
+  pub(crate) dead_status: Option<i32>,
+  #[serde(default)]
+  pub(crate) height: u16,
   pub(crate) id: String,
   pub(crate) index: usize,
+  #[serde(default, rename = "virtual")]
+  pub(crate) is_virtual: bool,
+  #[serde(default)]
+  pub(crate) last_activity: u64,
+  #[serde(default)]
+  pub(crate) marked: bool,
+  #[serde(default)]
+  pub(crate) pane_title: String,
   pub(crate) path: String,
+  #[serde(default)]
+  pub(crate) pid: u32,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub(crate) process: Option<ProcessInfo>,
+  #[serde(skip)]
+  pub(crate) server: Server,
   pub(crate) session: String,
+  #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+  pub(crate) sessions: BTreeMap<String, usize>,
+  #[serde(default)]
+  pub(crate) width: u16,
   pub(crate) window_index: usize,
+  #[serde(default)]
+  pub(crate) window_name: String,
+  #[serde(default)]
+  pub(crate) zoomed: bool,
 }
 
 impl Pane {
+  pub(crate) const FIELDS: [&'static str; 28] = [
+    "active",
+    "activity",
+    "bell",
+    "command",
+    "cpu",
+    "dead",
+    "descriptor",
+    "exit_status",
+    "height",
+    "id",
+    "index",
+    "last_activity",
+    "location",
+    "marked",
+    "memory",
+    "path",
+    "pid",
+    "process",
+    "process_pid",
+    "server",
+    "session",
+    "sessions",
+    "title",
+    "virtual",
+    "width",
+    "window",
+    "window_name",
+    "zoomed",
+  ];
+
   pub(crate) fn descriptor(&self) -> String {
     format!("{}:{}.{}", self.session, self.window_index, self.index)
   }
 
+  pub(crate) fn field(&self, name: &str) -> Option<String> {
+    let value = match name {
+      "active" => self.active.to_string(),
+      "activity" => self.activity.to_string(),
+      "bell" => self.bell.to_string(),
+      "command" => self.command.trim().to_string(),
+      "cpu" => self
+        .process
+        .as_ref()
+        .map(|process| process.cpu.to_string())
+        .unwrap_or_default(),
+      "dead" => self.dead.to_string(),
+      "descriptor" => self.descriptor(),
+      "exit_status" => self
+        .dead_status
+        .map(|status| status.to_string())
+        .unwrap_or_default(),
+      "height" => self.height.to_string(),
+      "id" => self.id.clone(),
+      "index" => self.index.to_string(),
+      "last_activity" => self.last_activity.to_string(),
+      "location" => self.location(),
+      "marked" => self.marked.to_string(),
+      "memory" => self
+        .process
+        .as_ref()
+        .map(|process| process.memory.to_string())
+        .unwrap_or_default(),
+      "path" => self.path.clone(),
+      "pid" => self.pid.to_string(),
+      "process" => self.process.as_ref().map_or_else(
+        || self.command.trim().to_string(),
+        |process| process.command_line.clone(),
+      ),
+      "process_pid" => self
+        .process
+        .as_ref()
+        .map(|process| process.pid.to_string())
+        .unwrap_or_default(),
+      "server" => self.server.label().unwrap_or_default(),
+      "session" => self.session.clone(),
+      "sessions" => {
+        if self.sessions.is_empty() {
+          self.session.clone()
+        } else {
+          self
+            .sessions
+            .keys()
+            .map(String::as_str)
+            .collect::<Vec<&str>>()
+            .join(",")
+        }
+      }
+      "title" => self.pane_title.clone(),
+      "virtual" => self.is_virtual.to_string(),
+      "width" => self.width.to_string(),
+      "window" => self.window_index.to_string(),
+      "window_name" => self.window_name.clone(),
+      "zoomed" => self.zoomed.to_string(),
+      _ => return None,
+    };
+
+    Some(value)
+  }
+
+  pub(crate) fn file_stem(&self) -> String {
+    let command = self.command.trim();
+
+    let mut stem = if command.is_empty() {
+      format!("{}-{}-{}", self.session, self.window_index, self.index)
+    } else {
+      format!(
+        "{}-{}-{}-{command}",
+        self.session, self.window_index, self.index
+      )
+    };
+
+    if let Some(label) = self.server.label() {
+      stem = format!("{label}-{stem}");
+    }
+
+    stem
+      .chars()
+      .map(|ch| {
+        if ch.is_ascii_alphanumeric() || matches!(ch, '-' | '_' | '.') {
+          ch
+        } else {
+          '_'
+        }
+      })
+      .collect()
+  }
+
   pub(crate) fn format<'a>() -> &'a str {
     concat!(
       "{",
+      "\"active\":#{?pane_active,true,false},",
+      "\"activity\":#{?window_activity_flag,true,false},",
+      "\"bell\":#{?window_bell_flag,true,false},",
       "\"command\":\"#{pane_current_command}\",",
+      "\"dead\":#{?pane_dead,true,false},",
+      "\"dead_status\":",
+      "#{?pane_dead,#{?#{!=:#{pane_dead_status},},#{pane_dead_status},null},null},",
+      "\"height\":#{pane_height},",
       "\"id\":\"#{pane_id}\",",
       "\"index\":#{pane_index},",
+      "\"last_activity\":#{window_activity},",
+      "\"marked\":#{?pane_marked,true,false},",
       "\"path\":\"#{pane_current_path}\",",
+      "\"pid\":#{pane_pid},",
       "\"session\":\"#{session_name}\",",
-      "\"window_index\":#{window_index}",
-      "}"
+      "\"width\":#{pane_width},",
+      "\"window_index\":#{window_index},",
+      "\"zoomed\":#{?window_zoomed_flag,true,false}",
+      "}\t#{window_name}\t#{pane_title}"
     )
   }
 
+  pub(crate) fn linked_sessions(&self) -> Vec<&str> {
+    self
+      .sessions
+      .keys()
+      .map(String::as_str)
+      .filter(|session| *session != self.session)
+      .collect()
+  }
+
+  pub(crate) fn location(&self) -> String {
+    self.server.describe(&self.descriptor())
+  }
+
+  pub(crate) fn matches_target(&self, target: &str) -> bool {
+    self.id == target
+      || !self.is_virtual
+        && (self.descriptor() == target || self.location() == target)
+  }
+
+  pub(crate) fn parse(line: &str) -> Result<Self> {
+    let Some((json, tail)) = line.split_once("}\t") else {
+      return Ok(serde_json::from_str::<Self>(line)?);
+    };
+
+    let mut pane = serde_json::from_str::<Self>(&line[..=json.len()])?;
+
+    let (window_name, title) = tail.split_once('\t').unwrap_or((tail, ""));
+
+    pane.pane_title = title.to_string();
+    pane.window_name = window_name.to_string();
+
+    Ok(pane)
+  }
+
+  pub(crate) fn plain_lines(&self) -> Vec<String> {
+    let text = self
+      .content
+      .into_text()
+      .unwrap_or_else(|_| Text::raw(self.content.clone()));
+
+    let mut lines = text
+      .lines
+      .iter()
+      .map(|line| {
+        line
+          .spans
+          .iter()
+          .map(|span| span.content.as_ref())
+          .collect::<String>()
+          .trim_end()
+          .to_string()
+      })
+      .collect::<Vec<String>>();
+
+    while lines.last().is_some_and(String::is_empty) {
+      lines.pop();
+    }
+
+    lines
+  }
+
+  pub(crate) fn target(&self) -> &str {
+    self.id.rsplit_once('/').map_or(&self.id, |(_, id)| id)
+  }
+
   pub(crate) fn title(&self) -> String {
     let command = self.command.trim();
 
-    if command.is_empty() {
-      return self.descriptor();
+    if self.is_virtual {
+      return format!("{} ({command})", self.window_name);
+    }
+
+    let mut title = self.location();
+
+    let linked = self.linked_sessions();
+
+    if !linked.is_empty() {
+      title = format!("{title} [{}]", linked.join(", "));
+    }
+
+    if !command.is_empty() {
+      title = format!("{title} ({command})");
+    }
+
+    if self.dead {
+      title = match self.dead_status {
+        Some(status) => format!("{title} [exited {status}]"),
+        None => format!("{title} [dead]"),
+      };
     }
 
-    format!("{} ({command})", self.descriptor())
+    title
   }
 }
 
@@ -57,6 +311,113 @@ mod tests {
     assert_eq!(pane.descriptor(), "session:2.1");
   }
 
+  #[test]
+  fn file_stem_includes_descriptor_and_command() {
+    let pane = Pane {
+      command: "codex".into(),
+      index: 1,
+      session: "work".into(),
+      window_index: 2,
+      ..Default::default()
+    };
+
+    assert_eq!(pane.file_stem(), "work-2-1-codex");
+  }
+
+  #[test]
+  fn file_stem_sanitizes_unsafe_characters() {
+    let pane = Pane {
+      command: " ".into(),
+      session: "my/session name".into(),
+      ..Default::default()
+    };
+
+    assert_eq!(pane.file_stem(), "my_session_name-0-0");
+  }
+
+  #[test]
+  fn panes_on_other_servers_are_qualified() {
+    let server = Server::Name("work".into());
+
+    let pane = Pane {
+      command: "bash".into(),
+      id: server.qualify("%3"),
+      session: "agents".into(),
+      server,
+      ..Default::default()
+    };
+
+    assert_eq!(pane.target(), "%3");
+    assert_eq!(pane.file_stem(), "work-agents-0-0-bash");
+    assert_eq!(pane.descriptor(), "agents:0.0");
+    assert_eq!(pane.title(), "work/agents:0.0 (bash)");
+    assert!(pane.matches_target("work/agents:0.0"));
+  }
+
+  #[test]
+  fn parse_reads_metadata_and_title() {
+    let pane = Pane::parse(concat!(
+      r#"{"active":true,"command":"bash","dead":true,"dead_status":2,"#,
+      r#""id":"%1","index":0,"last_activity":1700000000,"path":"/tmp","#,
+      r#""pid":42,"session":"s","window_index":1,"zoomed":true}"#,
+      "\tbuild \"ci\" \\ {x}\tsay \"hi\"\t}"
+    ))
+    .unwrap();
+
+    assert!(pane.active && pane.dead && pane.zoomed);
+    assert_eq!(pane.window_name, "build \"ci\" \\ {x}");
+    assert_eq!(pane.dead_status, Some(2));
+    assert_eq!(pane.pane_title, "say \"hi\"\t}");
+    assert_eq!(pane.field("pid").as_deref(), Some("42"));
+    assert_eq!(pane.field("last_activity").as_deref(), Some("1700000000"));
+    assert_eq!(pane.field("title").as_deref(), Some("say \"hi\"\t}"));
+    assert_eq!(pane.field("unknown"), None);
+    assert_eq!(pane.title(), "s:1.0 (bash) [exited 2]");
+  }
+
+  #[test]
+  fn title_lists_linked_sessions() {
+    let pane = Pane {
+      command: "bash".into(),
+      session: "main".into(),
+      sessions: [
+        ("main".to_string(), 0),
+        ("mirror".to_string(), 0),
+        ("other".to_string(), 4),
+      ]
+      .into(),
+      ..Default::default()
+    };
+
+    assert_eq!(pane.title(), "main:0.0 [mirror, other] (bash)");
+    assert!(pane.matches_target("main:0.0"));
+  }
+
+  #[test]
+  fn matches_target_accepts_id_or_descriptor() {
+    let pane = Pane {
+      id: "%3".into(),
+      index: 1,
+      session: "session".into(),
+      window_index: 2,
+      ..Default::default()
+    };
+
+    assert!(pane.matches_target("%3"));
+    assert!(pane.matches_target("session:2.1"));
+    assert!(!pane.matches_target("session:2"));
+  }
+
+  #[test]
+  fn plain_lines_strips_styles_and_trailing_blank_lines() {
+    let pane = Pane {
+      content: "\x1b[31mred\x1b[0m  \nplain\n\n\n".into(),
+      ..Default::default()
+    };
+
+    assert_eq!(pane.plain_lines(), vec!["red", "plain"]);
+  }
+
   #[test]
   fn title_appends_command_when_present() {
     let pane = Pane {
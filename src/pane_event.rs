@@ -0,0 +1,108 @@
+use super::*;
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub(crate) enum PaneEvent {
+  Bell {
+    pane: Pane,
+  },
+  ContentChanged {
+    pane: Pane,
+  },
+  Idle {
+    idle_ms: u64,
+    pane: Pane,
+  },
+  PaneAdded {
+    pane: Pane,
+  },
+  PaneRemoved {
+    pane: Pane,
+  },
+  PatternMatched {
+    line: String,
+    pane: Pane,
+    pattern: String,
+  },
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct EventRecord<'a> {
+  #[serde(flatten)]
+  event: &'a PaneEvent,
+  timestamp: u64,
+  version: u32,
+}
+
+impl<'a> EventRecord<'a> {
+  pub(crate) const SCHEMA_VERSION: u32 = 1;
+
+  pub(crate) fn new(event: &'a PaneEvent, timestamp: SystemTime) -> Self {
+    Self {
+      event,
+      timestamp: timestamp
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| u64::try_from(duration.as_millis()).unwrap_or(u64::MAX))
+        .unwrap_or_default(),
+      version: Self::SCHEMA_VERSION,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use {super::*, serde_json::json};
+
+  #[test]
+  fn record_serializes_flat_versioned_object() {
+    let pane = Pane {
+      content: "hidden".into(),
+      id: "%1".into(),
+      session: "session".into(),
+      ..Default::default()
+    };
+
+    let event = PaneEvent::PatternMatched {
+      line: "error: boom".into(),
+      pane,
+      pattern: "error".into(),
+    };
+
+    assert_eq!(
+      serde_json::to_value(EventRecord::new(
+        &event,
+        UNIX_EPOCH + Duration::from_millis(1500)
+      ))
+      .unwrap(),
+      json!({
+        "line": "error: boom",
+        "pane": {
+          "active": false,
+          "activity": false,
+          "bell": false,
+          "command": "",
+          "dead": false,
+          "dead_status": null,
+          "height": 0,
+          "id": "%1",
+          "index": 0,
+          "last_activity": 0,
+          "marked": false,
+          "pane_title": "",
+          "path": "",
+          "pid": 0,
+          "session": "session",
+          "virtual": false,
+          "width": 0,
+          "window_index": 0,
+          "window_name": "",
+          "zoomed": false,
+        },
+        "pattern": "error",
+        "timestamp": 1500,
+        "type": "pattern_matched",
+        "version": 1,
+      })
+    );
+  }
+}
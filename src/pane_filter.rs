@@ -0,0 +1,109 @@
+use super::*;
+
+#[derive(Clone, Debug)]
+enum Comparison {
+  Equal(String),
+  Match(Regex),
+  NotEqual(String),
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct PaneFilter {
+  comparison: Comparison,
+  field: String,
+}
+
+impl PaneFilter {
+  pub(crate) fn matches(&self, pane: &Pane) -> bool {
+    let value = pane.field(&self.field).unwrap_or_default();
+
+    match &self.comparison {
+      Comparison::Equal(expected) => value == *expected,
+      Comparison::Match(regex) => regex.is_match(&value),
+      Comparison::NotEqual(expected) => value != *expected,
+    }
+  }
+
+  pub(crate) fn parse(filter: &str) -> Result<Self> {
+    let Some(position) = filter.find(['=', '!', '~']) else {
+      bail!(
+        "invalid filter `{filter}`, expected FIELD=VALUE, FIELD!=VALUE or \
+         FIELD~REGEX"
+      );
+    };
+
+    let (field, rest) = filter.split_at(position);
+
+    let field = field.trim();
+
+    if !Pane::FIELDS.contains(&field) {
+      bail!(
+        "unknown pane field `{field}`, expected one of: {}",
+        Pane::FIELDS.join(", ")
+      );
+    }
+
+    let comparison = if let Some(value) = rest.strip_prefix("!=") {
+      Comparison::NotEqual(value.to_string())
+    } else if let Some(pattern) = rest.strip_prefix('~') {
+      Comparison::Match(
+        Regex::new(pattern)
+          .with_context(|| format!("invalid filter pattern `{pattern}`"))?,
+      )
+    } else if let Some(value) = rest.strip_prefix('=') {
+      Comparison::Equal(value.to_string())
+    } else {
+      bail!("invalid filter `{filter}`, expected =, != or ~ after {field}");
+    };
+
+    Ok(Self {
+      comparison,
+      field: field.to_string(),
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn pane() -> Pane {
+    Pane {
+      command: "claude".into(),
+      dead: true,
+      dead_status: Some(1),
+      pane_title: "Fix the build".into(),
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn filters_compare_pane_fields() {
+    assert!(
+      PaneFilter::parse("command=claude")
+        .unwrap()
+        .matches(&pane())
+    );
+    assert!(
+      !PaneFilter::parse("command!=claude")
+        .unwrap()
+        .matches(&pane())
+    );
+    assert!(PaneFilter::parse("dead=true").unwrap().matches(&pane()));
+    assert!(PaneFilter::parse("exit_status=1").unwrap().matches(&pane()));
+    assert!(
+      PaneFilter::parse("title~(?i)build")
+        .unwrap()
+        .matches(&pane())
+    );
+    assert!(!PaneFilter::parse("zoomed=true").unwrap().matches(&pane()));
+  }
+
+  #[test]
+  fn parse_rejects_unknown_fields_and_operators() {
+    assert!(PaneFilter::parse("colour=red").is_err());
+    assert!(PaneFilter::parse("command").is_err());
+    assert!(PaneFilter::parse("command!claude").is_err());
+    assert!(PaneFilter::parse("title~(").is_err());
+  }
+}
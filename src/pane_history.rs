@@ -0,0 +1,135 @@
+use super::*;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct Snapshot {
+  pub(crate) captured_at: DateTime<Local>,
+  pub(crate) content: String,
+}
+
+#[derive(Debug)]
+pub(crate) struct PaneHistory {
+  budget: usize,
+  snapshots: BTreeMap<String, VecDeque<Snapshot>>,
+}
+
+impl PaneHistory {
+  pub(crate) fn new(budget: usize) -> Self {
+    Self {
+      budget,
+      snapshots: BTreeMap::new(),
+    }
+  }
+
+  pub(crate) fn record(&mut self, panes: &[Pane], now: DateTime<Local>) {
+    self
+      .snapshots
+      .retain(|pane_id, _| panes.iter().any(|pane| &pane.id == pane_id));
+
+    if self.budget == 0 {
+      return;
+    }
+
+    for pane in panes {
+      let snapshots = self.snapshots.entry(pane.id.clone()).or_default();
+
+      if snapshots
+        .back()
+        .is_some_and(|snapshot| snapshot.content == pane.content)
+      {
+        continue;
+      }
+
+      snapshots.push_back(Snapshot {
+        captured_at: now,
+        content: pane.content.clone(),
+      });
+
+      let mut size = snapshots
+        .iter()
+        .map(|snapshot| snapshot.content.len())
+        .sum::<usize>();
+
+      while size > self.budget && snapshots.len() > 1 {
+        if let Some(evicted) = snapshots.pop_front() {
+          size -= evicted.content.len();
+        }
+      }
+    }
+  }
+
+  pub(crate) fn snapshots(&self, pane_id: &str) -> Option<&VecDeque<Snapshot>> {
+    self.snapshots.get(pane_id)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn pane(content: &str) -> Pane {
+    Pane {
+      content: content.into(),
+      id: "%0".into(),
+      ..Default::default()
+    }
+  }
+
+  fn contents(history: &PaneHistory) -> Vec<String> {
+    history
+      .snapshots("%0")
+      .unwrap()
+      .iter()
+      .map(|snapshot| snapshot.content.clone())
+      .collect()
+  }
+
+  #[test]
+  fn unchanged_content_is_recorded_once() {
+    let mut history = PaneHistory::new(1024);
+
+    history.record(&[pane("one")], Local::now());
+    history.record(&[pane("one")], Local::now());
+    history.record(&[pane("two")], Local::now());
+
+    assert_eq!(contents(&history), vec!["one", "two"]);
+  }
+
+  #[test]
+  fn oldest_snapshots_are_evicted_over_budget() {
+    let mut history = PaneHistory::new(6);
+
+    history.record(&[pane("one")], Local::now());
+    history.record(&[pane("two")], Local::now());
+    history.record(&[pane("three")], Local::now());
+
+    assert_eq!(contents(&history), vec!["three"]);
+  }
+
+  #[test]
+  fn latest_snapshot_is_kept_even_when_over_budget() {
+    let mut history = PaneHistory::new(1);
+
+    history.record(&[pane("large")], Local::now());
+
+    assert_eq!(contents(&history), vec!["large"]);
+  }
+
+  #[test]
+  fn removed_panes_are_forgotten() {
+    let mut history = PaneHistory::new(1024);
+
+    history.record(&[pane("one")], Local::now());
+    history.record(&[], Local::now());
+
+    assert_eq!(history.snapshots("%0"), None);
+  }
+
+  #[test]
+  fn zero_budget_disables_history() {
+    let mut history = PaneHistory::new(0);
+
+    history.record(&[pane("one")], Local::now());
+
+    assert_eq!(history.snapshots("%0"), None);
+  }
+}
@@ -0,0 +1,94 @@
+use super::*;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct PaneSort {
+  descending: bool,
+  field: String,
+}
+
+impl PaneSort {
+  fn compare(&self, a: &Pane, b: &Pane) -> Ordering {
+    let (a, b) = (
+      a.field(&self.field).unwrap_or_default(),
+      b.field(&self.field).unwrap_or_default(),
+    );
+
+    let ordering = match (a.parse::<i64>(), b.parse::<i64>()) {
+      (Ok(a), Ok(b)) => a.cmp(&b),
+      _ => a.cmp(&b),
+    };
+
+    if self.descending {
+      ordering.reverse()
+    } else {
+      ordering
+    }
+  }
+
+  pub(crate) fn parse(sort: &str) -> Result<Self> {
+    let (field, descending) = match sort.split_once(':') {
+      Some((field, "asc")) => (field, false),
+      Some((field, "desc")) => (field, true),
+      Some((_, order)) => {
+        bail!("invalid sort order `{order}`, expected asc or desc")
+      }
+      None => (sort, false),
+    };
+
+    if !Pane::FIELDS.contains(&field) {
+      bail!(
+        "unknown pane field `{field}`, expected one of: {}",
+        Pane::FIELDS.join(", ")
+      );
+    }
+
+    Ok(Self {
+      descending,
+      field: field.to_string(),
+    })
+  }
+
+  pub(crate) fn sort(&self, panes: &mut [Pane]) {
+    panes.sort_by(|a, b| self.compare(a, b));
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn panes() -> Vec<Pane> {
+    [(9, "b"), (10, "a"), (2, "c")]
+      .into_iter()
+      .map(|(pid, id)| Pane {
+        id: id.into(),
+        pid,
+        ..Default::default()
+      })
+      .collect()
+  }
+
+  fn ids(panes: &[Pane]) -> Vec<&str> {
+    panes.iter().map(|pane| pane.id.as_str()).collect()
+  }
+
+  #[test]
+  fn numeric_fields_sort_numerically() {
+    let mut panes = panes();
+
+    PaneSort::parse("pid").unwrap().sort(&mut panes);
+    assert_eq!(ids(&panes), ["c", "b", "a"]);
+
+    PaneSort::parse("pid:desc").unwrap().sort(&mut panes);
+    assert_eq!(ids(&panes), ["a", "b", "c"]);
+
+    PaneSort::parse("id:asc").unwrap().sort(&mut panes);
+    assert_eq!(ids(&panes), ["a", "b", "c"]);
+  }
+
+  #[test]
+  fn parse_rejects_unknown_fields_and_orders() {
+    assert!(PaneSort::parse("colour").is_err());
+    assert!(PaneSort::parse("pid:up").is_err());
+  }
+}
@@ -0,0 +1,304 @@
+use super::*;
+
+#[derive(Debug)]
+struct TrackedPane {
+  idle: bool,
+  last_change: Instant,
+  pane: Pane,
+}
+
+#[derive(Debug)]
+pub(crate) struct PaneTracker {
+  idle_after: Duration,
+  panes: BTreeMap<String, TrackedPane>,
+  patterns: Vec<Regex>,
+}
+
+impl PaneTracker {
+  pub(crate) fn idle_for(&self, pane_id: &str, now: Instant) -> Duration {
+    self.panes.get(pane_id).map_or(Duration::ZERO, |tracked| {
+      now.saturating_duration_since(tracked.last_change)
+    })
+  }
+
+  pub(crate) fn is_idle(&self, pane_id: &str) -> bool {
+    self.panes.get(pane_id).is_some_and(|tracked| tracked.idle)
+  }
+
+  fn matched_lines(
+    &self,
+    pane: &Pane,
+    previous_content: &str,
+  ) -> Vec<PaneEvent> {
+    let mut events = Vec::new();
+
+    for pattern in &self.patterns {
+      let mut seen = HashMap::<&str, usize>::new();
+
+      for line in previous_content.lines().filter(|l| pattern.is_match(l)) {
+        *seen.entry(line).or_default() += 1;
+      }
+
+      for line in pane.content.lines().filter(|l| pattern.is_match(l)) {
+        if let Some(count) = seen.get_mut(line).filter(|count| **count > 0) {
+          *count -= 1;
+          continue;
+        }
+
+        events.push(PaneEvent::PatternMatched {
+          line: line.to_string(),
+          pane: pane.clone(),
+          pattern: pattern.to_string(),
+        });
+      }
+    }
+
+    events
+  }
+
+  pub(crate) fn new(idle_after: Duration, patterns: Vec<Regex>) -> Self {
+    Self {
+      idle_after,
+      panes: BTreeMap::new(),
+      patterns,
+    }
+  }
+
+  pub(crate) fn update(
+    &mut self,
+    panes: &[Pane],
+    now: Instant,
+  ) -> Vec<PaneEvent> {
+    let mut events = Vec::new();
+
+    let removed = self
+      .panes
+      .keys()
+      .filter(|id| !panes.iter().any(|pane| &pane.id == *id))
+      .cloned()
+      .collect::<Vec<String>>();
+
+    for id in removed {
+      if let Some(tracked) = self.panes.remove(&id) {
+        events.push(PaneEvent::PaneRemoved { pane: tracked.pane });
+      }
+    }
+
+    for pane in panes {
+      let Some(tracked) = self.panes.get(&pane.id) else {
+        events.push(PaneEvent::PaneAdded { pane: pane.clone() });
+        events.extend(self.matched_lines(pane, ""));
+
+        if pane.bell {
+          events.push(PaneEvent::Bell { pane: pane.clone() });
+        }
+
+        self.panes.insert(
+          pane.id.clone(),
+          TrackedPane {
+            idle: false,
+            last_change: now,
+            pane: pane.clone(),
+          },
+        );
+
+        continue;
+      };
+
+      let (changed, rang) = (
+        tracked.pane.content != pane.content,
+        pane.bell && !tracked.pane.bell,
+      );
+
+      if changed {
+        events.push(PaneEvent::ContentChanged { pane: pane.clone() });
+        events.extend(self.matched_lines(pane, &tracked.pane.content));
+      }
+
+      if rang {
+        events.push(PaneEvent::Bell { pane: pane.clone() });
+      }
+
+      let Some(tracked) = self.panes.get_mut(&pane.id) else {
+        continue;
+      };
+
+      if changed {
+        tracked.idle = false;
+        tracked.last_change = now;
+      } else if !tracked.idle {
+        let idle_for = now.saturating_duration_since(tracked.last_change);
+
+        if idle_for >= self.idle_after {
+          tracked.idle = true;
+
+          events.push(PaneEvent::Idle {
+            idle_ms: u64::try_from(idle_for.as_millis()).unwrap_or(u64::MAX),
+            pane: pane.clone(),
+          });
+        }
+      }
+
+      tracked.pane = pane.clone();
+    }
+
+    events
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn pane(id: &str, content: &str) -> Pane {
+    Pane {
+      content: content.into(),
+      id: id.into(),
+      session: "session".into(),
+      ..Default::default()
+    }
+  }
+
+  fn event_types(events: &[PaneEvent]) -> Vec<&'static str> {
+    events
+      .iter()
+      .map(|event| match event {
+        PaneEvent::Bell { .. } => "bell",
+        PaneEvent::ContentChanged { .. } => "content_changed",
+        PaneEvent::Idle { .. } => "idle",
+        PaneEvent::PaneAdded { .. } => "pane_added",
+        PaneEvent::PaneRemoved { .. } => "pane_removed",
+        PaneEvent::PatternMatched { .. } => "pattern_matched",
+      })
+      .collect()
+  }
+
+  #[test]
+  fn first_snapshot_adds_every_pane() {
+    let mut tracker = PaneTracker::new(Duration::from_secs(5), Vec::new());
+
+    let events =
+      tracker.update(&[pane("%0", "one"), pane("%1", "two")], Instant::now());
+
+    assert_eq!(event_types(&events), vec!["pane_added", "pane_added"]);
+  }
+
+  #[test]
+  fn removed_and_changed_panes_are_reported() {
+    let mut tracker = PaneTracker::new(Duration::from_secs(5), Vec::new());
+
+    let now = Instant::now();
+
+    tracker.update(&[pane("%0", "one"), pane("%1", "two")], now);
+
+    let events = tracker.update(&[pane("%0", "one!")], now);
+
+    assert_eq!(
+      event_types(&events),
+      vec!["pane_removed", "content_changed"]
+    );
+  }
+
+  #[test]
+  fn idle_is_reported_once_until_content_changes() {
+    let mut tracker = PaneTracker::new(Duration::from_secs(5), Vec::new());
+
+    let start = Instant::now();
+
+    tracker.update(&[pane("%0", "one")], start);
+
+    assert!(
+      tracker
+        .update(&[pane("%0", "one")], start + Duration::from_secs(1))
+        .is_empty()
+    );
+
+    let events =
+      tracker.update(&[pane("%0", "one")], start + Duration::from_secs(6));
+
+    assert_eq!(
+      events,
+      vec![PaneEvent::Idle {
+        idle_ms: 6000,
+        pane: pane("%0", "one"),
+      }]
+    );
+
+    assert!(
+      tracker
+        .update(&[pane("%0", "one")], start + Duration::from_secs(20))
+        .is_empty()
+    );
+
+    let events =
+      tracker.update(&[pane("%0", "two")], start + Duration::from_secs(21));
+
+    assert_eq!(event_types(&events), vec!["content_changed"]);
+  }
+
+  #[test]
+  fn idle_state_is_queryable() {
+    let mut tracker = PaneTracker::new(Duration::from_secs(5), Vec::new());
+
+    let start = Instant::now();
+
+    tracker.update(&[pane("%0", "one")], start);
+
+    assert!(!tracker.is_idle("%0"));
+
+    tracker.update(&[pane("%0", "one")], start + Duration::from_secs(6));
+
+    assert!(tracker.is_idle("%0"));
+
+    assert_eq!(
+      tracker.idle_for("%0", start + Duration::from_secs(8)),
+      Duration::from_secs(8)
+    );
+
+    assert_eq!(tracker.idle_for("%9", start), Duration::ZERO);
+  }
+
+  #[test]
+  fn pattern_matches_only_new_lines() {
+    let mut tracker = PaneTracker::new(
+      Duration::from_secs(5),
+      vec![Regex::new("error").unwrap()],
+    );
+
+    let now = Instant::now();
+
+    tracker.update(&[pane("%0", "ok\nerror: one")], now);
+
+    let events = tracker.update(&[pane("%0", "error: one\nerror: two")], now);
+
+    assert_eq!(
+      events[1..],
+      [PaneEvent::PatternMatched {
+        line: "error: two".into(),
+        pane: pane("%0", "error: one\nerror: two"),
+        pattern: "error".into(),
+      }]
+    );
+  }
+
+  #[test]
+  fn bell_is_reported_when_flag_is_raised() {
+    let mut tracker = PaneTracker::new(Duration::from_secs(5), Vec::new());
+
+    let now = Instant::now();
+
+    tracker.update(&[pane("%0", "one")], now);
+
+    let ringing = Pane {
+      bell: true,
+      ..pane("%0", "one")
+    };
+
+    assert_eq!(
+      event_types(&tracker.update(std::slice::from_ref(&ringing), now)),
+      vec!["bell"]
+    );
+
+    assert!(tracker.update(&[ringing], now).is_empty());
+  }
+}
@@ -0,0 +1,257 @@
+use super::*;
+
+#[derive(Debug, Default)]
+pub(crate) struct Picker {
+  pub(crate) include_content: bool,
+  pub(crate) query: String,
+  pub(crate) selected: usize,
+}
+
+impl Picker {
+  const CONTENT_LINES: usize = 5;
+
+  pub(crate) fn handle_key(&mut self, key: KeyEvent, panes: &[&Pane]) {
+    let control = key.modifiers.contains(KeyModifiers::CONTROL);
+
+    match key.code {
+      KeyCode::Char('n') if control => self.move_selection(panes, 1),
+      KeyCode::Char('p') if control => self.move_selection(panes, -1),
+      KeyCode::Char('t') if control => {
+        self.include_content = !self.include_content;
+        self.selected = 0;
+      }
+      KeyCode::Char('u') if control => {
+        self.query.clear();
+        self.selected = 0;
+      }
+      KeyCode::Char(ch) if !control => {
+        self.query.push(ch);
+        self.selected = 0;
+      }
+      KeyCode::Backspace => {
+        self.query.pop();
+        self.selected = 0;
+      }
+      KeyCode::Down => self.move_selection(panes, 1),
+      KeyCode::Up => self.move_selection(panes, -1),
+      _ => {}
+    }
+  }
+
+  fn haystack(&self, pane: &Pane) -> String {
+    let mut haystack =
+      format!("{} {} {}", pane.title(), pane.window_name, pane.path);
+
+    if self.include_content {
+      let lines = pane.plain_lines();
+
+      for line in &lines[lines.len().saturating_sub(Self::CONTENT_LINES)..] {
+        haystack.push(' ');
+        haystack.push_str(line);
+      }
+    }
+
+    haystack
+  }
+
+  pub(crate) fn matches<'a>(&self, panes: &[&'a Pane]) -> Vec<&'a Pane> {
+    let mut scored = panes
+      .iter()
+      .copied()
+      .filter_map(|pane| {
+        fuzzy::score(&self.query, &self.haystack(pane))
+          .map(|score| (score, pane))
+      })
+      .collect::<Vec<(i64, &Pane)>>();
+
+    scored.sort_by_key(|(score, _)| Reverse(*score));
+
+    scored.into_iter().map(|(_, pane)| pane).collect()
+  }
+
+  fn move_selection(&mut self, panes: &[&Pane], delta: isize) {
+    let count = self.matches(panes).len();
+
+    self.selected = self
+      .selected
+      .min(count.saturating_sub(1))
+      .saturating_add_signed(delta)
+      .min(count.saturating_sub(1));
+  }
+
+  pub(crate) fn render(&self, frame: &mut Frame, area: Rect, panes: &[&Pane]) {
+    let matches = self.matches(panes);
+
+    let block = Block::default()
+      .title(if self.include_content {
+        "find pane (content)"
+      } else {
+        "find pane"
+      })
+      .title_bottom("enter select · ctrl-f focus · ctrl-t content")
+      .borders(Borders::ALL)
+      .border_type(BorderType::Rounded)
+      .border_style(Style::default().fg(Color::Cyan));
+
+    let inner = block.inner(area);
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(block, area);
+
+    let [input_area, list_area] = Layout::default()
+      .direction(Direction::Vertical)
+      .constraints([Constraint::Length(1), Constraint::Fill(1)])
+      .areas(inner);
+
+    let prompt = format!("> {}", self.query);
+
+    frame.set_cursor_position((
+      input_area.x.saturating_add(
+        u16::try_from(Line::from(prompt.as_str()).width()).unwrap_or(u16::MAX),
+      ),
+      input_area.y,
+    ));
+
+    frame.render_widget(Paragraph::new(prompt), input_area);
+
+    let list = List::new(matches.iter().map(|pane| {
+      Line::from(vec![
+        Span::raw(pane.title()),
+        Span::styled(
+          format!("  {}  {}", pane.window_name, pane.path),
+          Style::default().fg(Color::DarkGray),
+        ),
+      ])
+    }))
+    .highlight_style(Style::default().fg(Color::Black).bg(Color::Cyan));
+
+    let mut state = ListState::default().with_selected(
+      (!matches.is_empty())
+        .then(|| self.selected.min(matches.len().saturating_sub(1))),
+    );
+
+    frame.render_stateful_widget(list, list_area, &mut state);
+  }
+
+  pub(crate) fn selection<'a>(&self, panes: &[&'a Pane]) -> Option<&'a Pane> {
+    let matches = self.matches(panes);
+
+    matches
+      .get(self.selected.min(matches.len().saturating_sub(1)))
+      .copied()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn pane(id: &str, session: &str, path: &str, content: &str) -> Pane {
+    Pane {
+      command: "bash".into(),
+      content: content.into(),
+      id: id.into(),
+      path: path.into(),
+      session: session.into(),
+      ..Default::default()
+    }
+  }
+
+  fn panes() -> Vec<Pane> {
+    vec![
+      pane("%0", "notes", "/home/me/notes", "shopping list"),
+      pane(
+        "%1",
+        "api",
+        "/home/me/projects/api",
+        "error: connection refused",
+      ),
+      pane(
+        "%2",
+        "web",
+        "/home/me/projects/web",
+        "compiled successfully",
+      ),
+    ]
+  }
+
+  fn press(picker: &mut Picker, panes: &[&Pane], code: KeyCode) {
+    picker.handle_key(KeyEvent::new(code, KeyModifiers::NONE), panes);
+  }
+
+  fn ids(panes: &[&Pane]) -> Vec<String> {
+    panes.iter().map(|pane| pane.id.clone()).collect()
+  }
+
+  #[test]
+  fn empty_query_keeps_every_pane_in_order() {
+    let panes = panes();
+
+    let panes = panes.iter().collect::<Vec<&Pane>>();
+
+    assert_eq!(ids(&Picker::default().matches(&panes)), ["%0", "%1", "%2"]);
+  }
+
+  #[test]
+  fn query_filters_by_title_and_path() {
+    let panes = panes();
+
+    let panes = panes.iter().collect::<Vec<&Pane>>();
+
+    let mut picker = Picker::default();
+
+    for ch in "projects".chars() {
+      press(&mut picker, &panes, KeyCode::Char(ch));
+    }
+
+    assert_eq!(ids(&picker.matches(&panes)), ["%1", "%2"]);
+
+    press(&mut picker, &panes, KeyCode::Down);
+
+    assert_eq!(picker.selection(&panes).unwrap().id, "%2");
+
+    press(&mut picker, &panes, KeyCode::Down);
+
+    assert_eq!(picker.selection(&panes).unwrap().id, "%2");
+  }
+
+  #[test]
+  fn content_is_only_searched_when_enabled() {
+    let panes = panes();
+
+    let panes = panes.iter().collect::<Vec<&Pane>>();
+
+    let mut picker = Picker {
+      query: "refused".into(),
+      ..Default::default()
+    };
+
+    assert!(picker.matches(&panes).is_empty());
+
+    picker.handle_key(
+      KeyEvent::new(KeyCode::Char('t'), KeyModifiers::CONTROL),
+      &panes,
+    );
+
+    assert_eq!(ids(&picker.matches(&panes)), ["%1"]);
+  }
+
+  #[test]
+  fn backspace_edits_query_and_resets_selection() {
+    let panes = panes();
+
+    let panes = panes.iter().collect::<Vec<&Pane>>();
+
+    let mut picker = Picker {
+      query: "webx".into(),
+      selected: 2,
+      ..Default::default()
+    };
+
+    press(&mut picker, &panes, KeyCode::Backspace);
+
+    assert_eq!(picker.query, "web");
+    assert_eq!(picker.selected, 0);
+    assert_eq!(picker.selection(&panes).unwrap().id, "%2");
+  }
+}
@@ -0,0 +1,191 @@
+use super::*;
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub(crate) struct ProcessInfo {
+  pub(crate) children: usize,
+  pub(crate) command_line: String,
+  pub(crate) cpu: u32,
+  pub(crate) memory: u64,
+  pub(crate) pid: u32,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct ProcessMonitor {
+  samples: HashMap<u32, (Instant, u64)>,
+}
+
+impl ProcessMonitor {
+  const CLOCK_TICKS: u64 = 100;
+
+  fn children(pid: u32) -> usize {
+    fs::read_to_string(format!("/proc/{pid}/task/{pid}/children"))
+      .map(|children| children.split_whitespace().count())
+      .unwrap_or_default()
+  }
+
+  fn command_line(pid: u32) -> Option<String> {
+    let bytes = fs::read(format!("/proc/{pid}/cmdline")).ok()?;
+
+    let command_line = bytes
+      .split(|byte| *byte == 0)
+      .filter(|argument| !argument.is_empty())
+      .map(String::from_utf8_lossy)
+      .collect::<Vec<Cow<str>>>()
+      .join(" ");
+
+    (!command_line.is_empty()).then_some(command_line)
+  }
+
+  fn cpu(&mut self, pid: u32, ticks: u64, now: Instant) -> u32 {
+    let previous = self.samples.insert(pid, (now, ticks));
+
+    let Some((sampled, previous_ticks)) = previous else {
+      return 0;
+    };
+
+    let elapsed = now.saturating_duration_since(sampled).as_millis();
+
+    if elapsed == 0 {
+      return 0;
+    }
+
+    let cpu = u128::from(ticks.saturating_sub(previous_ticks)) * 100_000
+      / u128::from(Self::CLOCK_TICKS)
+      / elapsed;
+
+    u32::try_from(cpu).unwrap_or(u32::MAX)
+  }
+
+  fn inspect(&mut self, pid: u32, now: Instant) -> Option<ProcessInfo> {
+    let stat = Self::stat(pid)?;
+
+    let foreground = stat
+      .foreground_group
+      .filter(|group| *group != pid)
+      .and_then(|group| Some((group, Self::stat(group)?)));
+
+    let (pid, stat) = foreground.unwrap_or((pid, stat));
+
+    Some(ProcessInfo {
+      children: Self::children(pid),
+      command_line: Self::command_line(pid).unwrap_or(stat.name),
+      cpu: self.cpu(pid, stat.ticks, now),
+      memory: Self::memory(pid).unwrap_or_default(),
+      pid,
+    })
+  }
+
+  fn memory(pid: u32) -> Option<u64> {
+    Self::parse_memory(&fs::read_to_string(format!("/proc/{pid}/status")).ok()?)
+  }
+
+  fn parse_memory(status: &str) -> Option<u64> {
+    status
+      .lines()
+      .find_map(|line| line.strip_prefix("VmRSS:"))?
+      .split_whitespace()
+      .next()?
+      .parse()
+      .ok()
+  }
+
+  fn parse_stat(stat: &str) -> Option<Stat> {
+    let (head, rest) = stat.rsplit_once(')')?;
+
+    let name = head.split_once('(')?.1.to_string();
+
+    let fields = rest.split_whitespace().collect::<Vec<&str>>();
+
+    let foreground_group = fields.get(5)?.parse::<i64>().ok()?;
+
+    Some(Stat {
+      foreground_group: u32::try_from(foreground_group)
+        .ok()
+        .filter(|group| *group > 0),
+      name,
+      ticks: fields.get(11)?.parse::<u64>().ok()?
+        + fields.get(12)?.parse::<u64>().ok()?,
+    })
+  }
+
+  pub(crate) fn sample(&mut self, panes: &mut [Pane]) {
+    let now = Instant::now();
+
+    for pane in panes.iter_mut().filter(|pane| pane.pid > 0) {
+      pane.process = self.inspect(pane.pid, now);
+    }
+
+    self.samples.retain(|_, (sampled, _)| *sampled == now);
+  }
+
+  fn stat(pid: u32) -> Option<Stat> {
+    Self::parse_stat(&fs::read_to_string(format!("/proc/{pid}/stat")).ok()?)
+  }
+}
+
+#[derive(Debug)]
+struct Stat {
+  foreground_group: Option<u32>,
+  name: String,
+  ticks: u64,
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parse_stat_reads_foreground_group_and_cpu_ticks() {
+    let stat = ProcessMonitor::parse_stat(
+      "4242 (node (agent)) S 4200 4242 4200 34817 4300 4194304 1 0 0 0 \
+       150 50 0 0 20 0 11 0 100 0 0",
+    )
+    .unwrap();
+
+    assert_eq!(stat.name, "node (agent)");
+    assert_eq!(stat.foreground_group, Some(4300));
+    assert_eq!(stat.ticks, 200);
+
+    assert_eq!(
+      ProcessMonitor::parse_stat("1 (init) S 0 1 1 0 -1 0 0 0 0 0 5 5")
+        .unwrap()
+        .foreground_group,
+      None
+    );
+  }
+
+  #[test]
+  fn parse_memory_reads_resident_set_size() {
+    assert_eq!(
+      ProcessMonitor::parse_memory("Name:\tnode\nVmRSS:\t  123456 kB\n"),
+      Some(123_456)
+    );
+    assert_eq!(ProcessMonitor::parse_memory("Name:\tkthreadd\n"), None);
+  }
+
+  #[test]
+  fn cpu_is_measured_between_samples() {
+    let mut monitor = ProcessMonitor::default();
+
+    let start = Instant::now();
+
+    assert_eq!(monitor.cpu(7, 1000, start), 0);
+    assert_eq!(monitor.cpu(7, 1050, start + Duration::from_secs(1)), 50);
+  }
+
+  #[cfg(target_os = "linux")]
+  #[test]
+  fn sample_inspects_pane_processes() {
+    let mut panes = vec![Pane {
+      pid: process::id(),
+      ..Default::default()
+    }];
+
+    ProcessMonitor::default().sample(&mut panes);
+
+    let process = panes[0].process.as_ref().unwrap();
+
+    assert!(!process.command_line.is_empty());
+    assert!(process.memory > 0);
+  }
+}
@@ -0,0 +1,250 @@
+use super::*;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum PaneAction {
+  Kill,
+  Rename,
+  Respawn,
+  Spawn,
+  Split,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum PromptField {
+  Command,
+  Directory,
+  Session,
+}
+
+#[derive(Debug)]
+pub(crate) struct Prompt {
+  pub(crate) action: PaneAction,
+  pub(crate) directory: String,
+  pub(crate) field: PromptField,
+  pub(crate) input: String,
+  pub(crate) pane: Pane,
+  pub(crate) session: String,
+}
+
+impl Prompt {
+  pub(crate) fn cycle_template<'a>(
+    &mut self,
+    mut names: impl Iterator<Item = &'a String> + Clone,
+  ) {
+    let next = names
+      .clone()
+      .skip_while(|name| **name != self.input)
+      .nth(1)
+      .or_else(|| names.next());
+
+    if let Some(next) = next {
+      self.input.clone_from(next);
+    }
+  }
+
+  fn fields(&self) -> &'static [PromptField] {
+    match self.action {
+      PaneAction::Spawn => &[
+        PromptField::Session,
+        PromptField::Directory,
+        PromptField::Command,
+      ],
+      PaneAction::Split => &[PromptField::Directory, PromptField::Command],
+      _ => &[PromptField::Command],
+    }
+  }
+
+  pub(crate) fn focus(&mut self, forward: bool) {
+    let fields = self.fields();
+
+    let index = fields
+      .iter()
+      .position(|field| *field == self.field)
+      .unwrap_or_default();
+
+    self.field = fields[if forward {
+      (index + 1) % fields.len()
+    } else {
+      (index + fields.len() - 1) % fields.len()
+    }];
+  }
+
+  pub(crate) fn focused_mut(&mut self) -> &mut String {
+    match self.field {
+      PromptField::Command => &mut self.input,
+      PromptField::Directory => &mut self.directory,
+      PromptField::Session => &mut self.session,
+    }
+  }
+
+  pub(crate) fn is_text_input(&self) -> bool {
+    matches!(
+      self.action,
+      PaneAction::Rename | PaneAction::Spawn | PaneAction::Split
+    )
+  }
+
+  pub(crate) fn message(&self) -> String {
+    match self.action {
+      PaneAction::Kill => format!("Kill {}? (y/n)", self.pane.title()),
+      PaneAction::Rename => format!(
+        "Rename window {}:{} to: {}",
+        self.pane.session, self.pane.window_index, self.input
+      ),
+      PaneAction::Respawn => {
+        format!("Respawn {}, killing its process? (y/n)", self.pane.title())
+      }
+      PaneAction::Spawn => format!(
+        "New window in session {} dir {} command {} (↑/↓ switch field)",
+        self.show(PromptField::Session),
+        self.show(PromptField::Directory),
+        self.show(PromptField::Command)
+      ),
+      PaneAction::Split => format!(
+        "Split {} dir {} command {} (↑/↓ switch field)",
+        self.pane.title(),
+        self.show(PromptField::Directory),
+        self.show(PromptField::Command)
+      ),
+    }
+  }
+
+  pub(crate) fn new(action: PaneAction, pane: Pane) -> Self {
+    Self {
+      directory: pane.path.clone(),
+      field: PromptField::Command,
+      input: if action == PaneAction::Rename {
+        pane.window_name.clone()
+      } else {
+        String::new()
+      },
+      session: pane.session.clone(),
+      action,
+      pane,
+    }
+  }
+
+  pub(crate) fn render(&self, frame: &mut Frame, area: Rect) {
+    let area = Rect::new(
+      area.x,
+      area.y.saturating_add(area.height.saturating_sub(1)),
+      area.width,
+      area.height.min(1),
+    );
+
+    frame.render_widget(Clear, area);
+
+    frame.render_widget(
+      Paragraph::new(self.message())
+        .style(Style::default().fg(Color::Black).bg(Color::Yellow)),
+      area,
+    );
+  }
+
+  fn show(&self, field: PromptField) -> String {
+    let value = match field {
+      PromptField::Command => &self.input,
+      PromptField::Directory => &self.directory,
+      PromptField::Session => &self.session,
+    };
+
+    if field == self.field {
+      format!("[{value}▏]")
+    } else {
+      format!("[{value}]")
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn pane() -> Pane {
+    Pane {
+      command: "codex".into(),
+      session: "work".into(),
+      window_index: 2,
+      window_name: "agents".into(),
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn rename_prompt_starts_with_window_name() {
+    let prompt = Prompt::new(PaneAction::Rename, pane());
+
+    assert_eq!(prompt.message(), "Rename window work:2 to: agents");
+  }
+
+  #[test]
+  fn cycle_template_wraps_around_names() {
+    let names = ["claude".to_string(), "codex".to_string()];
+
+    let mut prompt = Prompt::new(PaneAction::Spawn, pane());
+
+    prompt.cycle_template(names.iter());
+    assert_eq!(prompt.input, "claude");
+
+    prompt.cycle_template(names.iter());
+    assert_eq!(prompt.input, "codex");
+
+    prompt.cycle_template(names.iter());
+    assert_eq!(prompt.input, "claude");
+  }
+
+  #[test]
+  fn spawn_prompt_edits_session_directory_and_command() {
+    let mut prompt = Prompt::new(
+      PaneAction::Spawn,
+      Pane {
+        path: "/src".into(),
+        ..pane()
+      },
+    );
+
+    prompt.focused_mut().push_str("claude");
+    prompt.focus(false);
+    prompt.focused_mut().push_str("/api");
+    prompt.focus(false);
+    prompt.focused_mut().clear();
+    prompt.focused_mut().push_str("agents");
+
+    assert_eq!(prompt.input, "claude");
+    assert_eq!(prompt.directory, "/src/api");
+    assert_eq!(prompt.session, "agents");
+
+    prompt.focus(false);
+    assert_eq!(prompt.field, PromptField::Command);
+
+    assert_eq!(
+      prompt.message(),
+      "New window in session [agents] dir [/src/api] command [claude▏] (↑/↓ \
+       switch field)"
+    );
+  }
+
+  #[test]
+  fn split_prompt_has_no_session_field() {
+    let mut prompt = Prompt::new(PaneAction::Split, pane());
+
+    prompt.focus(true);
+    assert_eq!(prompt.field, PromptField::Directory);
+
+    prompt.focus(true);
+    assert_eq!(prompt.field, PromptField::Command);
+  }
+
+  #[test]
+  fn destructive_prompts_ask_for_confirmation() {
+    assert_eq!(
+      Prompt::new(PaneAction::Kill, pane()).message(),
+      "Kill work:2.0 (codex)? (y/n)"
+    );
+
+    assert_eq!(
+      Prompt::new(PaneAction::Respawn, pane()).message(),
+      "Respawn work:2.0 (codex), killing its process? (y/n)"
+    );
+  }
+}
@@ -0,0 +1,237 @@
+use super::*;
+
+#[derive(Debug)]
+struct Recording {
+  file: File,
+  lines: Vec<String>,
+}
+
+impl Recording {
+  fn append(&mut self, pane: &Pane, timestamp: DateTime<Local>) -> Result {
+    let lines = pane.plain_lines();
+
+    let (_, start) = line_diff::appended_from(&self.lines, &lines);
+
+    for line in &lines[start..] {
+      writeln!(
+        self.file,
+        "[{}] {line}",
+        timestamp.format(Recorder::TIMESTAMP_FORMAT)
+      )?;
+    }
+
+    self.lines = lines;
+
+    Ok(())
+  }
+}
+
+#[derive(Debug)]
+pub(crate) struct Recorder {
+  directory: PathBuf,
+  record_all: bool,
+  recordings: BTreeMap<String, Recording>,
+  seen: HashSet<String>,
+}
+
+impl Recorder {
+  const TIMESTAMP_FORMAT: &'static str = "%Y-%m-%d %H:%M:%S";
+
+  pub(crate) fn is_recording(&self, pane_id: &str) -> bool {
+    self.recordings.contains_key(pane_id)
+  }
+
+  pub(crate) fn new(directory: PathBuf, record_all: bool) -> Self {
+    Self {
+      directory,
+      record_all,
+      recordings: BTreeMap::new(),
+      seen: HashSet::new(),
+    }
+  }
+
+  pub(crate) fn record(
+    &mut self,
+    panes: &[Pane],
+    timestamp: DateTime<Local>,
+  ) -> Result {
+    self
+      .recordings
+      .retain(|pane_id, _| panes.iter().any(|pane| &pane.id == pane_id));
+
+    self
+      .seen
+      .retain(|pane_id| panes.iter().any(|pane| &pane.id == pane_id));
+
+    for pane in panes {
+      if !self.seen.insert(pane.id.clone()) {
+        if let Some(recording) = self.recordings.get_mut(&pane.id) {
+          recording.append(pane, timestamp)?;
+        }
+      } else if self.record_all {
+        self.start(pane, timestamp)?;
+      }
+    }
+
+    Ok(())
+  }
+
+  pub(crate) fn start(
+    &mut self,
+    pane: &Pane,
+    timestamp: DateTime<Local>,
+  ) -> Result {
+    fs::create_dir_all(&self.directory).with_context(|| {
+      format!("failed to create directory {}", self.directory.display())
+    })?;
+
+    let path = self.directory.join(format!("{}.log", pane.file_stem()));
+
+    let file = OpenOptions::new()
+      .append(true)
+      .create(true)
+      .open(&path)
+      .with_context(|| format!("failed to open {}", path.display()))?;
+
+    let mut recording = Recording {
+      file,
+      lines: Vec::new(),
+    };
+
+    recording.append(pane, timestamp)?;
+
+    self.recordings.insert(pane.id.clone(), recording);
+
+    Ok(())
+  }
+
+  pub(crate) fn toggle(
+    &mut self,
+    pane: &Pane,
+    timestamp: DateTime<Local>,
+  ) -> Result {
+    if self.recordings.remove(&pane.id).is_none() {
+      self.start(pane, timestamp)?;
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn directory(name: &str) -> PathBuf {
+    let directory =
+      env::temp_dir().join(format!("spymux-recorder-{}-{name}", process::id()));
+
+    fs::remove_dir_all(&directory).ok();
+
+    directory
+  }
+
+  fn pane(content: &str) -> Pane {
+    Pane {
+      command: "bash".into(),
+      content: content.into(),
+      id: "%0".into(),
+      session: "session".into(),
+      ..Default::default()
+    }
+  }
+
+  fn timestamp() -> DateTime<Local> {
+    DateTime::from_timestamp(0, 0)
+      .unwrap()
+      .with_timezone(&Local)
+  }
+
+  #[test]
+  fn record_appends_only_new_lines() {
+    let directory = directory("append");
+
+    let mut recorder = Recorder::new(directory.clone(), false);
+
+    recorder.start(&pane("one\ntwo\n"), timestamp()).unwrap();
+
+    recorder
+      .record(&[pane("two\nthree\nfour\n")], timestamp())
+      .unwrap();
+
+    recorder
+      .record(&[pane("two\nthree\nfour\n")], timestamp())
+      .unwrap();
+
+    let stamp = timestamp().format(Recorder::TIMESTAMP_FORMAT).to_string();
+
+    assert_eq!(
+      fs::read_to_string(directory.join("session-0-0-bash.log")).unwrap(),
+      format!(
+        "[{stamp}] one\n[{stamp}] two\n[{stamp}] three\n[{stamp}] four\n"
+      )
+    );
+
+    fs::remove_dir_all(directory).unwrap();
+  }
+
+  #[test]
+  fn record_all_starts_recording_new_panes() {
+    let directory = directory("all");
+
+    let mut recorder = Recorder::new(directory.clone(), true);
+
+    recorder.record(&[pane("one\n")], timestamp()).unwrap();
+    assert!(recorder.is_recording("%0"));
+
+    let added = Pane {
+      id: "%1".into(),
+      index: 1,
+      ..pane("two\n")
+    };
+
+    recorder
+      .record(&[pane("one\n"), added.clone()], timestamp())
+      .unwrap();
+    assert!(recorder.is_recording("%1"));
+
+    recorder.toggle(&added, timestamp()).unwrap();
+    recorder
+      .record(&[pane("one\n"), added], timestamp())
+      .unwrap();
+    assert!(!recorder.is_recording("%1"));
+
+    assert!(directory.join("session-0-1-bash.log").is_file());
+
+    fs::remove_dir_all(directory).unwrap();
+  }
+
+  #[test]
+  fn toggle_stops_recording() {
+    let directory = directory("toggle");
+
+    let mut recorder = Recorder::new(directory.clone(), false);
+
+    recorder.toggle(&pane(""), timestamp()).unwrap();
+    assert!(recorder.is_recording("%0"));
+
+    recorder.toggle(&pane(""), timestamp()).unwrap();
+    assert!(!recorder.is_recording("%0"));
+
+    fs::remove_dir_all(directory).unwrap();
+  }
+
+  #[test]
+  fn record_forgets_removed_panes() {
+    let directory = directory("removed");
+
+    let mut recorder = Recorder::new(directory.clone(), false);
+
+    recorder.start(&pane("one"), timestamp()).unwrap();
+    recorder.record(&[], timestamp()).unwrap();
+
+    assert!(!recorder.is_recording("%0"));
+
+    fs::remove_dir_all(directory).unwrap();
+  }
+}
@@ -0,0 +1,131 @@
+use super::*;
+
+#[derive(
+  Clone,
+  Debug,
+  Default,
+  Deserialize,
+  Eq,
+  Hash,
+  Ord,
+  PartialEq,
+  PartialOrd,
+  Serialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum Server {
+  #[default]
+  Default,
+  Name(String),
+  Path(PathBuf),
+}
+
+impl Server {
+  pub(crate) fn arguments(&self) -> Vec<String> {
+    match self {
+      Self::Default => Vec::new(),
+      Self::Name(name) => vec!["-L".into(), name.clone()],
+      Self::Path(path) => vec!["-S".into(), path.display().to_string()],
+    }
+  }
+
+  pub(crate) fn current_pane_id(servers: &[Self]) -> Option<String> {
+    let pane_id = env::var("TMUX_PANE").ok()?;
+
+    servers
+      .iter()
+      .find(|server| server.is_current())
+      .map(|server| server.qualify(&pane_id))
+  }
+
+  pub(crate) fn describe(&self, name: &str) -> String {
+    match self.label() {
+      Some(label) => format!("{label}/{name}"),
+      None => name.to_string(),
+    }
+  }
+
+  pub(crate) fn is_current(&self) -> bool {
+    env::var("TMUX").is_ok_and(|tmux| {
+      tmux
+        .split(',')
+        .next()
+        .is_some_and(|socket| self.owns_socket(Path::new(socket)))
+    })
+  }
+
+  pub(crate) fn label(&self) -> Option<String> {
+    match self {
+      Self::Default => None,
+      Self::Name(name) => Some(name.clone()),
+      Self::Path(path) => Some(
+        path
+          .file_name()
+          .unwrap_or(path.as_os_str())
+          .to_string_lossy()
+          .to_string(),
+      ),
+    }
+  }
+
+  fn owns_socket(&self, socket: &Path) -> bool {
+    match self {
+      Self::Default => true,
+      Self::Name(name) => socket
+        .file_name()
+        .is_some_and(|file_name| file_name == name.as_str()),
+      Self::Path(path) => {
+        path == socket
+          || fs::canonicalize(path).is_ok_and(|path| {
+            fs::canonicalize(socket).is_ok_and(|socket| path == socket)
+          })
+      }
+    }
+  }
+
+  pub(crate) fn qualify(&self, name: &str) -> String {
+    match self {
+      Self::Default => name.to_string(),
+      Self::Name(server) => format!("{server}/{name}"),
+      Self::Path(path) => format!("{}/{name}", path.display()),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn default_server_needs_no_arguments() {
+    assert!(Server::Default.arguments().is_empty());
+    assert_eq!(Server::Default.qualify("%3"), "%3");
+  }
+
+  #[test]
+  fn servers_recognize_their_sockets() {
+    let socket = Path::new("/tmp/tmux-0/default");
+
+    assert!(Server::Default.owns_socket(socket));
+    assert!(!Server::Name("work".into()).owns_socket(socket));
+    assert!(!Server::Path("/tmp/work.sock".into()).owns_socket(socket));
+    assert!(
+      Server::Name("work".into()).owns_socket(Path::new("/tmp/tmux-0/work"))
+    );
+    assert!(Server::Path(socket.into()).owns_socket(socket));
+  }
+
+  #[test]
+  fn socket_servers_qualify_pane_ids() {
+    let (name, path) = (
+      Server::Name("work".into()),
+      Server::Path("/tmp/tmux.sock".into()),
+    );
+
+    assert_eq!(name.arguments(), ["-L", "work"]);
+    assert_eq!(path.arguments(), ["-S", "/tmp/tmux.sock"]);
+    assert_eq!(name.qualify("%3"), "work/%3");
+    assert_eq!(path.qualify("%3"), "/tmp/tmux.sock/%3");
+    assert_eq!(path.describe("work:1.0"), "tmux.sock/work:1.0");
+  }
+}
@@ -0,0 +1,388 @@
+use super::*;
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) enum SidebarNode {
+  #[default]
+  All,
+  Pane(String),
+  Session(String),
+  Window(String, usize),
+}
+
+impl SidebarNode {
+  pub(crate) fn contains(&self, pane: &Pane) -> bool {
+    match self {
+      Self::All => true,
+      Self::Pane(id) => pane.id == *id,
+      Self::Session(session) => pane.server.qualify(&pane.session) == *session,
+      Self::Window(session, window_index) => {
+        pane.server.qualify(&pane.session) == *session
+          && pane.window_index == *window_index
+      }
+    }
+  }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct SidebarEntry {
+  active: bool,
+  bell: bool,
+  count: usize,
+  depth: usize,
+  label: String,
+  node: SidebarNode,
+}
+
+impl SidebarEntry {
+  fn line(&self) -> Line<'static> {
+    let marker = if self.bell {
+      Span::styled("! ", Style::default().fg(Color::Yellow))
+    } else if self.active {
+      Span::styled("● ", Style::default().fg(Color::Green))
+    } else {
+      Span::raw("  ")
+    };
+
+    let label = if matches!(self.node, SidebarNode::Pane(_)) {
+      self.label.clone()
+    } else {
+      format!("{} ({})", self.label, self.count)
+    };
+
+    Line::from(vec![
+      marker,
+      Span::raw("  ".repeat(self.depth)),
+      label.into(),
+    ])
+  }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct Sidebar {
+  pub(crate) focused: bool,
+  pub(crate) selected: SidebarNode,
+  pub(crate) visible: bool,
+}
+
+impl Sidebar {
+  const WIDTH: u16 = 32;
+
+  pub(crate) fn entries(
+    panes: &[Pane],
+    tracker: &PaneTracker,
+  ) -> Vec<SidebarEntry> {
+    let mut sorted = panes.iter().collect::<Vec<&Pane>>();
+
+    sorted.sort_by(|a, b| {
+      (&a.server, &a.session, a.window_index, a.index).cmp(&(
+        &b.server,
+        &b.session,
+        b.window_index,
+        b.index,
+      ))
+    });
+
+    let mut entries = Vec::<SidebarEntry>::new();
+
+    for pane in sorted {
+      let (active, bell) = (!tracker.is_idle(&pane.id), pane.bell);
+
+      let nodes = [
+        (SidebarNode::All, 0, "all".to_string()),
+        (
+          SidebarNode::Session(pane.server.qualify(&pane.session)),
+          0,
+          pane.server.describe(&pane.session),
+        ),
+        (
+          SidebarNode::Window(
+            pane.server.qualify(&pane.session),
+            pane.window_index,
+          ),
+          1,
+          format!("{}: {}", pane.window_index, pane.window_name),
+        ),
+        (
+          SidebarNode::Pane(pane.id.clone()),
+          2,
+          format!("{}: {}", pane.index, pane.command),
+        ),
+      ];
+
+      for (node, depth, label) in nodes {
+        if let Some(entry) = entries.iter_mut().find(|entry| entry.node == node)
+        {
+          entry.active |= active;
+          entry.bell |= bell;
+          entry.count += 1;
+        } else {
+          entries.push(SidebarEntry {
+            active,
+            bell,
+            count: 1,
+            depth,
+            label,
+            node,
+          });
+        }
+      }
+    }
+
+    entries
+  }
+
+  pub(crate) fn filter<'a>(&self, panes: &'a [Pane]) -> Vec<&'a Pane> {
+    panes
+      .iter()
+      .filter(|pane| !self.visible || self.selected.contains(pane))
+      .collect()
+  }
+
+  pub(crate) fn move_selection(
+    &mut self,
+    entries: &[SidebarEntry],
+    direction: Movement,
+  ) {
+    let Some(current) =
+      entries.iter().position(|entry| entry.node == self.selected)
+    else {
+      self.selected = SidebarNode::All;
+      return;
+    };
+
+    let next = match direction {
+      Movement::Down => Some(current.saturating_add(1)),
+      Movement::Up => Some(current.saturating_sub(1)),
+      Movement::Left => entries[..current]
+        .iter()
+        .rposition(|entry| entry.depth < entries[current].depth),
+      Movement::Right => Some(current.saturating_add(1)).filter(|next| {
+        entries
+          .get(*next)
+          .is_some_and(|entry| entry.depth > entries[current].depth)
+      }),
+    };
+
+    if let Some(entry) = next.and_then(|next| entries.get(next)) {
+      self.selected = entry.node.clone();
+    }
+  }
+
+  pub(crate) fn render(
+    &self,
+    frame: &mut Frame,
+    area: Rect,
+    entries: &[SidebarEntry],
+  ) -> Vec<(SidebarNode, Rect)> {
+    let mut block = Block::default()
+      .title("tree")
+      .borders(Borders::ALL)
+      .border_type(BorderType::Rounded);
+
+    if self.focused {
+      block = block.border_style(Style::default().fg(Color::Cyan));
+    }
+
+    let list = List::new(entries.iter().map(SidebarEntry::line))
+      .highlight_style(Style::default().fg(Color::Black).bg(Color::Cyan))
+      .block(block);
+
+    let mut state = ListState::default().with_selected(
+      entries.iter().position(|entry| entry.node == self.selected),
+    );
+
+    frame.render_stateful_widget(list, area, &mut state);
+
+    let (rows_top, rows_bottom) = (
+      area.y.saturating_add(1),
+      area.y.saturating_add(area.height).saturating_sub(1),
+    );
+
+    entries
+      .iter()
+      .skip(state.offset())
+      .zip(rows_top..rows_bottom)
+      .map(|(entry, y)| {
+        (
+          entry.node.clone(),
+          Rect::new(area.x + 1, y, area.width.saturating_sub(2), 1),
+        )
+      })
+      .collect()
+  }
+
+  pub(crate) fn retain(&mut self, entries: &[SidebarEntry]) {
+    if !entries.iter().any(|entry| entry.node == self.selected) {
+      self.selected = SidebarNode::All;
+    }
+  }
+
+  pub(crate) fn split(&self, area: Rect) -> (Option<Rect>, Rect) {
+    if !self.visible {
+      return (None, area);
+    }
+
+    let [sidebar_area, body_area] = Layout::default()
+      .direction(Direction::Horizontal)
+      .constraints([
+        Constraint::Length(Self::WIDTH.min(area.width / 2)),
+        Constraint::Fill(1),
+      ])
+      .areas(area);
+
+    (Some(sidebar_area), body_area)
+  }
+
+  pub(crate) fn toggle(&mut self) {
+    self.visible = !self.visible;
+    self.focused = self.visible;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn pane(id: &str, session: &str, window_index: usize, index: usize) -> Pane {
+    Pane {
+      command: "bash".into(),
+      id: id.into(),
+      index,
+      session: session.into(),
+      window_index,
+      window_name: format!("w{window_index}"),
+      ..Default::default()
+    }
+  }
+
+  fn panes() -> Vec<Pane> {
+    vec![
+      pane("%2", "work", 1, 0),
+      pane("%0", "base", 0, 0),
+      pane("%1", "work", 0, 0),
+      pane("%3", "work", 1, 1),
+    ]
+  }
+
+  #[test]
+  fn entries_build_sorted_tree_with_counts() {
+    let tracker = PaneTracker::new(Duration::from_secs(5), Vec::new());
+
+    let entries = Sidebar::entries(&panes(), &tracker);
+
+    assert_eq!(
+      entries
+        .iter()
+        .map(|entry| (entry.depth, entry.label.as_str(), entry.count))
+        .collect::<Vec<(usize, &str, usize)>>(),
+      vec![
+        (0, "all", 4),
+        (0, "base", 1),
+        (1, "0: w0", 1),
+        (2, "0: bash", 1),
+        (0, "work", 3),
+        (1, "0: w0", 1),
+        (2, "0: bash", 1),
+        (1, "1: w1", 2),
+        (2, "0: bash", 1),
+        (2, "1: bash", 1),
+      ]
+    );
+  }
+
+  #[test]
+  fn entries_propagate_bell_to_ancestors() {
+    let mut panes = panes();
+
+    panes[3].bell = true;
+
+    let tracker = PaneTracker::new(Duration::from_secs(5), Vec::new());
+
+    let bells = Sidebar::entries(&panes, &tracker)
+      .into_iter()
+      .filter(|entry| entry.bell)
+      .map(|entry| entry.node)
+      .collect::<Vec<SidebarNode>>();
+
+    assert_eq!(
+      bells,
+      vec![
+        SidebarNode::All,
+        SidebarNode::Session("work".into()),
+        SidebarNode::Window("work".into(), 1),
+        SidebarNode::Pane("%3".into()),
+      ]
+    );
+  }
+
+  #[test]
+  fn filter_keeps_selected_subtree_while_visible() {
+    let panes = panes();
+
+    let mut sidebar = Sidebar {
+      selected: SidebarNode::Window("work".into(), 1),
+      ..Default::default()
+    };
+
+    assert_eq!(sidebar.filter(&panes).len(), 4);
+
+    sidebar.visible = true;
+
+    assert_eq!(
+      sidebar
+        .filter(&panes)
+        .iter()
+        .map(|pane| pane.id.as_str())
+        .collect::<Vec<&str>>(),
+      vec!["%2", "%3"]
+    );
+  }
+
+  #[test]
+  fn move_selection_walks_tree() {
+    let tracker = PaneTracker::new(Duration::from_secs(5), Vec::new());
+
+    let entries = Sidebar::entries(&panes(), &tracker);
+
+    let mut sidebar = Sidebar {
+      selected: SidebarNode::Session("work".into()),
+      ..Default::default()
+    };
+
+    sidebar.move_selection(&entries, Movement::Right);
+    assert_eq!(sidebar.selected, SidebarNode::Window("work".into(), 0));
+
+    sidebar.move_selection(&entries, Movement::Down);
+    sidebar.move_selection(&entries, Movement::Down);
+    assert_eq!(sidebar.selected, SidebarNode::Window("work".into(), 1));
+
+    sidebar.move_selection(&entries, Movement::Right);
+    sidebar.move_selection(&entries, Movement::Right);
+    assert_eq!(sidebar.selected, SidebarNode::Pane("%2".into()));
+
+    sidebar.move_selection(&entries, Movement::Left);
+    sidebar.move_selection(&entries, Movement::Left);
+    assert_eq!(sidebar.selected, SidebarNode::Session("work".into()));
+
+    sidebar.move_selection(&entries, Movement::Up);
+    sidebar.move_selection(&entries, Movement::Up);
+    sidebar.move_selection(&entries, Movement::Up);
+    sidebar.move_selection(&entries, Movement::Up);
+    sidebar.move_selection(&entries, Movement::Up);
+    assert_eq!(sidebar.selected, SidebarNode::All);
+  }
+
+  #[test]
+  fn retain_resets_missing_selection() {
+    let tracker = PaneTracker::new(Duration::from_secs(5), Vec::new());
+
+    let mut sidebar = Sidebar {
+      selected: SidebarNode::Session("gone".into()),
+      ..Default::default()
+    };
+
+    sidebar.retain(&Sidebar::entries(&panes(), &tracker));
+
+    assert_eq!(sidebar.selected, SidebarNode::All);
+  }
+}
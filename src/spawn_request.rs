@@ -0,0 +1,167 @@
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum SpawnTarget {
+  Session {
+    name: String,
+    window_name: Option<String>,
+  },
+  Split {
+    pane_id: String,
+    vertical: bool,
+  },
+  Window {
+    name: Option<String>,
+    session: Option<String>,
+  },
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct SpawnRequest {
+  pub(crate) command: String,
+  pub(crate) directory: String,
+  pub(crate) target: SpawnTarget,
+}
+
+impl SpawnRequest {
+  pub(crate) fn arguments(&self) -> Vec<String> {
+    let mut arguments = match &self.target {
+      SpawnTarget::Session { name, window_name } => {
+        let mut arguments = vec![
+          "new-session".to_string(),
+          "-d".to_string(),
+          "-s".to_string(),
+          name.clone(),
+        ];
+
+        if let Some(window_name) = window_name {
+          arguments.extend(["-n".to_string(), window_name.clone()]);
+        }
+
+        arguments
+      }
+      SpawnTarget::Split { pane_id, vertical } => vec![
+        "split-window".to_string(),
+        if *vertical { "-v" } else { "-h" }.to_string(),
+        "-t".to_string(),
+        pane_id.clone(),
+      ],
+      SpawnTarget::Window { name, session } => {
+        let mut arguments = vec!["new-window".to_string()];
+
+        if let Some(session) = session {
+          arguments.extend(["-t".to_string(), format!("{session}:")]);
+        }
+
+        if let Some(name) = name {
+          arguments.extend(["-n".to_string(), name.clone()]);
+        }
+
+        arguments
+      }
+    };
+
+    arguments.extend([
+      "-P".to_string(),
+      "-F".to_string(),
+      "#{pane_id}".to_string(),
+      "-c".to_string(),
+      self.directory.clone(),
+    ]);
+
+    if !self.command.trim().is_empty() {
+      arguments.push(self.command.clone());
+    }
+
+    arguments
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn window_request_targets_session() {
+    let request = SpawnRequest {
+      command: "codex --full-auto".into(),
+      directory: "/work/api".into(),
+      target: SpawnTarget::Window {
+        name: Some("api".into()),
+        session: Some("agents".into()),
+      },
+    };
+
+    assert_eq!(
+      request.arguments(),
+      [
+        "new-window",
+        "-t",
+        "agents:",
+        "-n",
+        "api",
+        "-P",
+        "-F",
+        "#{pane_id}",
+        "-c",
+        "/work/api",
+        "codex --full-auto"
+      ]
+    );
+  }
+
+  #[test]
+  fn session_request_creates_detached_session() {
+    let request = SpawnRequest {
+      command: "claude".into(),
+      directory: "/work".into(),
+      target: SpawnTarget::Session {
+        name: "agents".into(),
+        window_name: Some("web".into()),
+      },
+    };
+
+    assert_eq!(
+      request.arguments(),
+      [
+        "new-session",
+        "-d",
+        "-s",
+        "agents",
+        "-n",
+        "web",
+        "-P",
+        "-F",
+        "#{pane_id}",
+        "-c",
+        "/work",
+        "claude"
+      ]
+    );
+  }
+
+  #[test]
+  fn split_request_without_command_starts_shell() {
+    let request = SpawnRequest {
+      command: " ".into(),
+      directory: "/work".into(),
+      target: SpawnTarget::Split {
+        pane_id: "%3".into(),
+        vertical: true,
+      },
+    };
+
+    assert_eq!(
+      request.arguments(),
+      [
+        "split-window",
+        "-v",
+        "-t",
+        "%3",
+        "-P",
+        "-F",
+        "#{pane_id}",
+        "-c",
+        "/work"
+      ]
+    );
+  }
+}
@@ -1,17 +1,47 @@
 use super::*;
 
+mod down;
+mod dump;
+mod events;
+mod grep;
+mod record;
 mod resume;
+mod spawn;
+mod up;
 
 #[derive(Debug, Clone, Parser)]
 pub(crate) enum Subcommand {
+  #[clap(about = "Kill the windows declared in a workspace file")]
+  Down(down::Down),
+  #[clap(about = "Dump the scrollback of every pane to a directory")]
+  Dump(dump::Dump),
+  #[clap(about = "Stream pane lifecycle and activity events as JSON lines")]
+  Events(events::Events),
+  #[clap(about = "Search the scrollback of every pane")]
+  Grep(grep::Grep),
+  #[clap(about = "Record a pane as an asciicast v2 file")]
+  Record(record::Record),
   #[clap(about = "Resume a spymux session in another directory")]
-  Resume,
+  Resume(resume::Resume),
+  #[clap(about = "Start a command in a new tmux window or split")]
+  Spawn(spawn::Spawn),
+  #[clap(
+    about = "Create the windows declared in a workspace file and watch them"
+  )]
+  Up(up::Up),
 }
 
 impl Subcommand {
-  pub(crate) fn run(self) -> Result {
+  pub(crate) fn run(self, config: Config) -> Result {
     match self {
-      Self::Resume => resume::run(),
+      Self::Down(down) => down.run(config),
+      Self::Dump(dump) => dump.run(config),
+      Self::Events(events) => events.run(config),
+      Self::Grep(grep) => grep.run(config),
+      Self::Record(record) => record.run(config),
+      Self::Resume(resume) => resume.run(config),
+      Self::Spawn(spawn) => spawn.run(config),
+      Self::Up(up) => up.run(config),
     }
   }
 }
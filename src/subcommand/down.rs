@@ -0,0 +1,39 @@
+use super::*;
+
+#[derive(Debug, Clone, Parser)]
+pub(crate) struct Down {
+  #[clap(
+    value_name = "WORKSPACE",
+    help = "Workspace file, or the name of a file in the workspaces directory"
+  )]
+  workspace: String,
+}
+
+impl Down {
+  pub(crate) fn run(self, config: Config) -> Result {
+    let windows = Workspace::load(&self.workspace)?.windows();
+
+    let mut killed = HashSet::new();
+
+    let [server] = config.servers.as_slice() else {
+      bail!(
+        "`down` works on a single tmux server, pass at most one --socket-name \
+         or --socket-path"
+      );
+    };
+
+    for pane in Tmux::list_all_panes(slice::from_ref(server))? {
+      if !windows.contains(&(pane.session.clone(), pane.window_name.clone()))
+        || !killed.insert((pane.session.clone(), pane.window_index))
+      {
+        continue;
+      }
+
+      Tmux::kill_window(&pane)?;
+
+      println!("{}:{}", pane.session, pane.window_name);
+    }
+
+    Ok(())
+  }
+}
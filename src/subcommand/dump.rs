@@ -0,0 +1,97 @@
+use super::*;
+
+#[derive(Debug, Serialize)]
+struct IndexEntry<'a> {
+  file: String,
+  #[serde(flatten)]
+  pane: &'a Pane,
+}
+
+#[derive(Debug, Clone, Parser)]
+pub(crate) struct Dump {
+  #[clap(long, help = "Preserve ANSI escape codes in the dumped scrollback")]
+  ansi: bool,
+  #[clap(long, value_name = "DIR", help = "Directory to write the dump to")]
+  dir: PathBuf,
+}
+
+impl Dump {
+  fn file_name(pane: &Pane, taken: &mut HashSet<String>) -> String {
+    let stem = pane.file_stem();
+
+    let mut file = format!("{stem}.txt");
+
+    for suffix in 2.. {
+      if taken.insert(file.clone()) {
+        break;
+      }
+
+      file = format!("{stem}-{suffix}.txt");
+    }
+
+    file
+  }
+
+  pub(crate) fn run(self, config: Config) -> Result {
+    fs::create_dir_all(&self.dir).with_context(|| {
+      format!("failed to create directory {}", self.dir.display())
+    })?;
+
+    let panes = Tmux::list_all_panes(&config.servers)?;
+
+    let mut index = Vec::with_capacity(panes.len());
+
+    let mut taken = HashSet::new();
+
+    for pane in &panes {
+      let file = Self::file_name(pane, &mut taken);
+
+      let content = Tmux::capture_history(pane, None, self.ansi)?;
+
+      let path = self.dir.join(&file);
+
+      fs::write(&path, content)
+        .with_context(|| format!("failed to write {}", path.display()))?;
+
+      index.push(IndexEntry { file, pane });
+    }
+
+    let path = self.dir.join("index.json");
+
+    fs::write(&path, serde_json::to_string_pretty(&index)?)
+      .with_context(|| format!("failed to write {}", path.display()))?;
+
+    eprintln!("Dumped {} panes to {}", panes.len(), self.dir.display());
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn file_name_deduplicates_colliding_stems() {
+    let pane = |session: &str| Pane {
+      command: "bash".into(),
+      session: session.into(),
+      ..Default::default()
+    };
+
+    let mut taken = HashSet::new();
+
+    assert_eq!(
+      Dump::file_name(&pane("a/b"), &mut taken),
+      "a_b-0-0-bash.txt"
+    );
+    assert_eq!(
+      Dump::file_name(&pane("a_b"), &mut taken),
+      "a_b-0-0-bash-2.txt"
+    );
+    assert_eq!(
+      Dump::file_name(&pane("a b"), &mut taken),
+      "a_b-0-0-bash-3.txt"
+    );
+  }
+}
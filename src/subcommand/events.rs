@@ -0,0 +1,55 @@
+use super::*;
+
+#[derive(Debug, Clone, Parser)]
+pub(crate) struct Events {
+  #[clap(
+    long,
+    value_name = "MILLISECONDS",
+    default_value_t = 5000,
+    help = "Report a pane as idle after its content is unchanged for this long"
+  )]
+  idle_after: u64,
+  #[clap(
+    short,
+    long = "pattern",
+    value_name = "REGEX",
+    help = "Report newly printed lines matching a regular expression"
+  )]
+  patterns: Vec<Regex>,
+}
+
+impl Events {
+  pub(crate) fn run(self, config: Config) -> Result {
+    let mut tmux = Tmux::new(config.clone());
+
+    tmux.include_escape_codes = false;
+
+    if let Some(pane_id) = Server::current_pane_id(&config.servers) {
+      tmux.exclude_pane_id(&pane_id);
+    }
+
+    let mut tracker =
+      PaneTracker::new(Duration::from_millis(self.idle_after), self.patterns);
+
+    let mut stdout = io::stdout().lock();
+
+    loop {
+      tmux.capture()?;
+
+      for event in tracker.update(&tmux.panes, Instant::now()) {
+        let line =
+          serde_json::to_string(&EventRecord::new(&event, SystemTime::now()))?;
+
+        match writeln!(stdout, "{line}").and_then(|()| stdout.flush()) {
+          Ok(()) => {}
+          Err(error) if error.kind() == io::ErrorKind::BrokenPipe => {
+            return Ok(());
+          }
+          Err(error) => return Err(error.into()),
+        }
+      }
+
+      thread::sleep(config.refresh_rate);
+    }
+  }
+}
@@ -0,0 +1,184 @@
+use super::*;
+
+#[derive(Debug, Clone, Parser)]
+pub(crate) struct Grep {
+  #[clap(
+    short = 'C',
+    long,
+    value_name = "LINES",
+    default_value_t = 0,
+    help = "Show this many lines of context around each match"
+  )]
+  context: usize,
+  #[clap(
+    long,
+    help = "Focus the pane of the first match and open copy mode at its line"
+  )]
+  focus: bool,
+  #[clap(short, long, help = "Search case insensitively")]
+  ignore_case: bool,
+  #[clap(value_name = "PATTERN", help = "Regular expression to search for")]
+  pattern: String,
+  #[clap(
+    long,
+    value_name = "LINES",
+    help = "Search at most this many lines of scrollback (default: all)"
+  )]
+  scrollback: Option<usize>,
+}
+
+impl Grep {
+  fn highlight(regex: &Regex, line: &str, is_match: bool) -> String {
+    if !is_match {
+      return line.to_string();
+    }
+
+    let (mut highlighted, mut last) = (String::new(), 0);
+
+    for found in regex.find_iter(line) {
+      highlighted.push_str(&line[last..found.start()]);
+      highlighted.push_str(&found.as_str().red().bold().to_string());
+      last = found.end();
+    }
+
+    highlighted.push_str(&line[last..]);
+
+    highlighted
+  }
+
+  fn matching_lines(
+    regex: &Regex,
+    lines: &[&str],
+    context: usize,
+  ) -> Vec<(usize, bool)> {
+    let mut selected: Vec<(usize, bool)> = Vec::new();
+
+    for (index, line) in lines.iter().enumerate() {
+      if !regex.is_match(line) {
+        continue;
+      }
+
+      let start = index.saturating_sub(context);
+      let end = index.saturating_add(context).min(lines.len() - 1);
+
+      for (line_index, line) in
+        lines.iter().enumerate().take(end + 1).skip(start)
+      {
+        if selected.last().is_none_or(|last| last.0 < line_index) {
+          selected.push((line_index, regex.is_match(line)));
+        }
+      }
+    }
+
+    selected
+  }
+
+  pub(crate) fn run(self, config: Config) -> Result {
+    let regex = RegexBuilder::new(&self.pattern)
+      .case_insensitive(self.ignore_case)
+      .build()?;
+
+    let current_pane_id = Server::current_pane_id(&config.servers);
+
+    let use_color = config.color_output && io::stdout().is_terminal();
+
+    let mut stdout = io::stdout().lock();
+
+    let mut first_match: Option<(Pane, usize)> = None;
+
+    for pane in Tmux::list_all_panes(&config.servers)? {
+      if current_pane_id.as_ref() == Some(&pane.id) {
+        continue;
+      }
+
+      let content = Tmux::capture_history(&pane, self.scrollback, false)?;
+
+      let lines = content.lines().collect::<Vec<&str>>();
+
+      let descriptor = pane.location();
+
+      let mut previous = None;
+
+      for (index, is_match) in
+        Self::matching_lines(&regex, &lines, self.context)
+      {
+        if self.context > 0
+          && previous.is_some_and(|previous: usize| previous + 1 < index)
+        {
+          writeln!(stdout, "--")?;
+        }
+
+        previous = Some(index);
+
+        let (separator, line) =
+          (if is_match { ':' } else { '-' }, lines[index]);
+
+        if use_color {
+          writeln!(
+            stdout,
+            "{}{separator}{}{separator}{}",
+            descriptor.as_str().magenta(),
+            (index + 1).to_string().green(),
+            Self::highlight(&regex, line, is_match),
+          )?;
+        } else {
+          writeln!(
+            stdout,
+            "{descriptor}{separator}{}{separator}{line}",
+            index + 1
+          )?;
+        }
+
+        if is_match && first_match.is_none() {
+          first_match = Some((pane.clone(), lines.len() - 1 - index));
+        }
+      }
+    }
+
+    if self.focus
+      && let Some((pane, lines_from_bottom)) = first_match
+    {
+      Tmux::focus_pane(&pane)?;
+      Tmux::scroll_to_line(&pane, lines_from_bottom)?;
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn matching_lines_without_context() {
+    assert_eq!(
+      Grep::matching_lines(
+        &Regex::new("error").unwrap(),
+        &["ok", "error: one", "ok", "error: two"],
+        0
+      ),
+      vec![(1, true), (3, true)]
+    );
+  }
+
+  #[test]
+  fn matching_lines_merges_overlapping_context() {
+    assert_eq!(
+      Grep::matching_lines(
+        &Regex::new("error").unwrap(),
+        &["a", "error", "b", "error", "c", "d", "e"],
+        1
+      ),
+      vec![(0, false), (1, true), (2, false), (3, true), (4, false)]
+    );
+  }
+
+  #[test]
+  fn matching_lines_clamps_context_to_bounds() {
+    assert_eq!(
+      Grep::matching_lines(&Regex::new("x").unwrap(), &["x", "a"], 3),
+      vec![(0, true), (1, false)]
+    );
+  }
+}
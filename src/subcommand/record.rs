@@ -0,0 +1,65 @@
+use super::*;
+
+#[derive(Debug, Clone, Parser)]
+pub(crate) struct Record {
+  #[clap(
+    long,
+    value_name = "SECONDS",
+    help = "Stop recording after this many seconds"
+  )]
+  duration: Option<u64>,
+  #[clap(
+    short,
+    long,
+    value_name = "FILE",
+    help = "File to write the recording to (default: <pane>.cast)"
+  )]
+  output: Option<PathBuf>,
+  #[clap(
+    value_name = "TARGET",
+    help = "Pane to record, as a pane id (%3) or descriptor (session:1.0)"
+  )]
+  target: String,
+}
+
+impl Record {
+  pub(crate) fn run(self, config: Config) -> Result {
+    let pane = Tmux::list_all_panes(&config.servers)?
+      .into_iter()
+      .find(|pane| pane.matches_target(&self.target))
+      .ok_or_else(|| anyhow!("unable to locate pane {}", self.target))?;
+
+    let tmux = Tmux::new(config.clone());
+
+    let Some(pane) = tmux.refresh_pane(&pane.id)? else {
+      bail!("pane {} exited before recording started", self.target);
+    };
+
+    let output = self
+      .output
+      .unwrap_or_else(|| PathBuf::from(format!("{}.cast", pane.file_stem())));
+
+    let mut recorder = CastRecorder::create(&output, &pane)?;
+
+    eprintln!(
+      "Recording {} to {}, press ctrl-c to stop",
+      pane.location(),
+      output.display()
+    );
+
+    let (started, duration) =
+      (Instant::now(), self.duration.map(Duration::from_secs));
+
+    while duration.is_none_or(|duration| started.elapsed() < duration) {
+      thread::sleep(config.refresh_rate);
+
+      let Some(pane) = tmux.refresh_pane(&pane.id)? else {
+        break;
+      };
+
+      recorder.record(&pane, Instant::now())?;
+    }
+
+    Ok(())
+  }
+}
@@ -1,28 +1,123 @@
 use super::*;
 
-pub(crate) fn run() -> Result {
-  let current_pane_id = env::var("TMUX_PANE").ok();
-
-  let mut panes = Tmux::list_panes_by_command(env!("CARGO_PKG_NAME"))?;
+#[derive(Debug, Clone, Parser)]
+pub(crate) struct Resume {
+  #[clap(
+    long,
+    help = "Select the pane with fzf instead of the built-in picker"
+  )]
+  fzf: bool,
+  #[clap(
+    long,
+    conflicts_with = "target",
+    help = "Resume the spymux pane with the highest pane id, i.e. the most \
+            recently created pane"
+  )]
+  last: bool,
+  #[clap(
+    value_name = "TARGET",
+    help = "Session name, pane id (%3) or descriptor (session:1.0) to resume"
+  )]
+  target: Option<String>,
+}
 
-  if let Some(current_pane_id) = current_pane_id {
-    panes.retain(|pane| pane.id != current_pane_id);
+impl Resume {
+  fn pane_number(pane: &Pane) -> usize {
+    pane
+      .target()
+      .trim_start_matches('%')
+      .parse::<usize>()
+      .unwrap_or_default()
   }
 
-  if panes.is_empty() {
-    bail!("no running spymux panes were found");
-  }
+  fn pick_pane(panes: &[Pane]) -> Result<Option<Pane>> {
+    let mut guard = TerminalGuard::new()?;
+
+    let mut picker = Picker::default();
+
+    let panes = panes.iter().collect::<Vec<&Pane>>();
+
+    loop {
+      guard
+        .terminal_mut()
+        .draw(|frame| picker.render(frame, frame.area(), &panes))?;
+
+      let Event::Key(key) = event::read()? else {
+        continue;
+      };
+
+      if key.kind != KeyEventKind::Press {
+        continue;
+      }
+
+      let control = key.modifiers.contains(KeyModifiers::CONTROL);
 
-  if panes.len() == 1 {
-    Tmux::focus_pane(&panes[0])?;
-    return Ok(());
+      match key.code {
+        KeyCode::Esc => return Ok(None),
+        KeyCode::Char('c') if control => return Ok(None),
+        KeyCode::Enter => return Ok(picker.selection(&panes).cloned()),
+        KeyCode::Char('f') if control => {
+          return Ok(picker.selection(&panes).cloned());
+        }
+        _ => picker.handle_key(key, &panes),
+      }
+    }
   }
 
-  if let Some(pane) = select_pane(&panes)? {
-    Tmux::focus_pane(&pane)?;
+  pub(crate) fn run(self, config: Config) -> Result {
+    let current_pane_id = Server::current_pane_id(&config.servers);
+
+    let mut panes =
+      Tmux::list_panes_by_command(&config.servers, env!("CARGO_PKG_NAME"))?;
+
+    if let Some(current_pane_id) = current_pane_id {
+      panes.retain(|pane| pane.id != current_pane_id);
+    }
+
+    if panes.is_empty() {
+      bail!("no running spymux panes were found");
+    }
+
+    if let Some(target) = &self.target {
+      panes = Self::select_target(panes, target);
+
+      if panes.is_empty() {
+        bail!("no running spymux pane matches {target}");
+      }
+    }
+
+    if self.last {
+      panes = panes
+        .into_iter()
+        .max_by_key(Self::pane_number)
+        .into_iter()
+        .collect();
+    }
+
+    if panes.len() == 1 {
+      Tmux::focus_pane(&panes[0])?;
+      return Ok(());
+    }
+
+    let selection = if self.fzf {
+      select_pane(&panes)?
+    } else {
+      Self::pick_pane(&panes)?
+    };
+
+    if let Some(pane) = selection {
+      Tmux::focus_pane(&pane)?;
+    }
+
+    Ok(())
   }
 
-  Ok(())
+  fn select_target(panes: Vec<Pane>, target: &str) -> Vec<Pane> {
+    panes
+      .into_iter()
+      .filter(|pane| pane.session == target || pane.matches_target(target))
+      .collect()
+  }
 }
 
 fn select_pane(panes: &[Pane]) -> Result<Option<Pane>> {
@@ -40,7 +135,7 @@ fn select_pane(panes: &[Pane]) -> Result<Option<Pane>> {
       writeln!(
         &mut stdin,
         "{}\t{}\t{}",
-        pane.descriptor(),
+        pane.location(),
         sanitize_path(&pane.path),
         pane.id
       )?;
@@ -88,6 +183,43 @@ fn sanitize_path(path: &str) -> String {
 mod tests {
   use super::*;
 
+  fn pane(id: &str, session: &str, window_index: usize) -> Pane {
+    Pane {
+      id: id.into(),
+      session: session.into(),
+      window_index,
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn select_target_matches_session_descriptor_or_id() {
+    let panes = vec![
+      pane("%1", "work", 0),
+      pane("%7", "work", 2),
+      pane("%4", "play", 0),
+    ];
+
+    let ids = |target: &str| {
+      Resume::select_target(panes.clone(), target)
+        .into_iter()
+        .map(|pane| pane.id)
+        .collect::<Vec<String>>()
+    };
+
+    assert_eq!(ids("work"), ["%1", "%7"]);
+    assert_eq!(ids("work:2.0"), ["%7"]);
+    assert_eq!(ids("%4"), ["%4"]);
+    assert!(ids("missing").is_empty());
+  }
+
+  #[test]
+  fn pane_number_orders_by_creation() {
+    assert_eq!(Resume::pane_number(&pane("%12", "work", 0)), 12);
+    assert_eq!(Resume::pane_number(&pane("work/%15", "work", 0)), 15);
+    assert_eq!(Resume::pane_number(&pane("bogus", "work", 0)), 0);
+  }
+
   #[test]
   fn sanitize_path_replaces_tabs() {
     assert_eq!(sanitize_path("/tmp\ttabs"), "/tmp tabs");
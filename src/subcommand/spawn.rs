@@ -0,0 +1,134 @@
+use super::*;
+
+#[derive(Debug, Clone, Parser)]
+pub(crate) struct Spawn {
+  #[clap(
+    value_name = "COMMAND",
+    index = 2,
+    last = true,
+    help = "Command to run in the new pane (default: your shell)"
+  )]
+  command: Vec<String>,
+  #[clap(
+    value_name = "DIR",
+    index = 1,
+    help = "Directory to start the new pane in"
+  )]
+  directory: PathBuf,
+  #[clap(
+    short,
+    long,
+    value_name = "SESSION",
+    conflicts_with = "split",
+    help = "Session to create the new window in"
+  )]
+  session: Option<String>,
+  #[clap(
+    long,
+    value_name = "PANE",
+    help = "Split this pane, as a pane id (%3) or descriptor (session:1.0)"
+  )]
+  split: Option<String>,
+  #[clap(
+    short,
+    long,
+    value_name = "NAME",
+    conflicts_with = "command",
+    help = "Run the command of this template from the config file"
+  )]
+  template: Option<String>,
+  #[clap(
+    long,
+    requires = "split",
+    help = "Place the new pane below the split pane instead of beside it"
+  )]
+  vertical: bool,
+}
+
+impl Spawn {
+  fn quote(argument: &str) -> String {
+    if !argument.is_empty()
+      && argument.chars().all(|ch| {
+        ch.is_ascii_alphanumeric() || matches!(ch, '-' | '_' | '.' | '/' | '=')
+      })
+    {
+      return argument.to_string();
+    }
+
+    format!("'{}'", argument.replace('\'', r"'\''"))
+  }
+
+  pub(crate) fn run(self, config: Config) -> Result {
+    let command = match &self.template {
+      Some(name) => config
+        .templates
+        .get(name)
+        .cloned()
+        .ok_or_else(|| anyhow!("unknown template {name}"))?,
+      None => self
+        .command
+        .iter()
+        .map(|argument| Self::quote(argument))
+        .collect::<Vec<String>>()
+        .join(" "),
+    };
+
+    let directory = fs::canonicalize(&self.directory).with_context(|| {
+      format!("failed to resolve directory {}", self.directory.display())
+    })?;
+
+    let (server, target) = match self.split {
+      Some(target) => {
+        let pane = Tmux::list_all_panes(&config.servers)?
+          .into_iter()
+          .find(|pane| pane.matches_target(&target))
+          .ok_or_else(|| anyhow!("unable to locate pane {target}"))?;
+
+        (
+          pane.server.clone(),
+          SpawnTarget::Split {
+            pane_id: pane.target().to_string(),
+            vertical: self.vertical,
+          },
+        )
+      }
+      None => (
+        config.servers.first().cloned().unwrap_or_default(),
+        SpawnTarget::Window {
+          name: None,
+          session: self.session,
+        },
+      ),
+    };
+
+    let pane_id = Tmux::spawn(
+      &server,
+      &SpawnRequest {
+        command,
+        directory: directory.to_string_lossy().to_string(),
+        target,
+      },
+    )?;
+
+    println!("{pane_id}");
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn quote_leaves_plain_words_alone() {
+    assert_eq!(Spawn::quote("--model=o3"), "--model=o3");
+  }
+
+  #[test]
+  fn quote_wraps_shell_metacharacters() {
+    assert_eq!(Spawn::quote("fix the bug"), "'fix the bug'");
+    assert_eq!(Spawn::quote("it's"), r"'it'\''s'");
+    assert_eq!(Spawn::quote(""), "''");
+  }
+}
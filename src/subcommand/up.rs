@@ -0,0 +1,110 @@
+use super::*;
+
+#[derive(Debug, Clone, Parser)]
+pub(crate) struct Up {
+  #[clap(
+    value_name = "WORKSPACE",
+    help = "Workspace file, or the name of a file in the workspaces directory"
+  )]
+  workspace: String,
+}
+
+impl Up {
+  fn create_window(
+    server: &Server,
+    session: &WorkspaceSession,
+    window: &WorkspaceWindow,
+    session_exists: bool,
+  ) -> Result {
+    let mut panes = window.panes(&session.root()).into_iter();
+
+    let Some((command, directory)) = panes.next() else {
+      return Ok(());
+    };
+
+    let pane_id = Tmux::spawn(
+      server,
+      &SpawnRequest {
+        command,
+        directory,
+        target: if session_exists {
+          SpawnTarget::Window {
+            name: Some(window.name.clone()),
+            session: Some(session.name.clone()),
+          }
+        } else {
+          SpawnTarget::Session {
+            name: session.name.clone(),
+            window_name: Some(window.name.clone()),
+          }
+        },
+      },
+    )?;
+
+    let layout = window.layout.as_deref().unwrap_or("tiled");
+
+    for (command, directory) in panes {
+      Tmux::spawn(
+        server,
+        &SpawnRequest {
+          command,
+          directory,
+          target: SpawnTarget::Split {
+            pane_id: pane_id.clone(),
+            vertical: false,
+          },
+        },
+      )?;
+
+      Tmux::select_layout(server, &pane_id, layout)?;
+    }
+
+    if let Some(layout) = &window.layout {
+      Tmux::select_layout(server, &pane_id, layout)?;
+    }
+
+    Ok(())
+  }
+
+  pub(crate) fn run(self, config: Config) -> Result {
+    let workspace = Workspace::load(&self.workspace)?;
+
+    let [server] = config.servers.as_slice() else {
+      bail!(
+        "`up` works on a single tmux server, pass at most one --socket-name \
+         or --socket-path"
+      );
+    };
+
+    let server = server.clone();
+
+    let panes = Tmux::list_all_panes(slice::from_ref(&server))?;
+
+    for session in &workspace.sessions {
+      let mut session_exists =
+        panes.iter().any(|pane| pane.session == session.name);
+
+      for window in &session.windows {
+        if panes.iter().any(|pane| {
+          pane.session == session.name && pane.window_name == window.name
+        }) {
+          continue;
+        }
+
+        Self::create_window(&server, session, window, session_exists)
+          .with_context(|| {
+            format!("failed to create window {}:{}", session.name, window.name)
+          })?;
+
+        session_exists = true;
+      }
+    }
+
+    App::new(Config {
+      servers: vec![server],
+      workspace_windows: Some(workspace.windows()),
+      ..config
+    })?
+    .run()
+  }
+}
@@ -0,0 +1,359 @@
+use super::*;
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum TitlePosition {
+  Center,
+  #[default]
+  Left,
+  Right,
+}
+
+impl TitlePosition {
+  pub(crate) fn alignment(self) -> Alignment {
+    match self {
+      Self::Center => Alignment::Center,
+      Self::Left => Alignment::Left,
+      Self::Right => Alignment::Right,
+    }
+  }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Segment {
+  Literal(String),
+  Placeholder(String),
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct TitleFormat {
+  #[serde(skip)]
+  branches: RefCell<HashMap<String, (Instant, Option<String>)>>,
+  pub(crate) position: TitlePosition,
+  #[serde(
+    deserialize_with = "TitleFormat::deserialize_template",
+    rename = "template"
+  )]
+  segments: Option<Vec<Segment>>,
+}
+
+impl TitleFormat {
+  const BRANCH_TTL: Duration = Duration::from_secs(5);
+  const PLACEHOLDERS: [&'static str; 5] =
+    ["branch", "default", "flags", "idle", "short_path"];
+
+  fn branch(&self, path: &str) -> Option<String> {
+    if path.is_empty() {
+      return None;
+    }
+
+    let mut branches = self.branches.borrow_mut();
+
+    if let Some((checked, branch)) = branches.get(path)
+      && checked.elapsed() < Self::BRANCH_TTL
+    {
+      return branch.clone();
+    }
+
+    let branch = Self::git_branch(Path::new(path));
+
+    branches.insert(path.to_string(), (Instant::now(), branch.clone()));
+
+    branch
+  }
+
+  fn deserialize_template<'de, D: Deserializer<'de>>(
+    deserializer: D,
+  ) -> Result<Option<Vec<Segment>>, D::Error> {
+    Self::segments(&String::deserialize(deserializer)?)
+      .map(Some)
+      .map_err(|error| serde::de::Error::custom(format!("{error:#}")))
+  }
+
+  fn flags(pane: &Pane) -> String {
+    [
+      (pane.active, '*'),
+      (pane.zoomed, 'Z'),
+      (pane.marked, 'M'),
+      (pane.bell, '!'),
+      (pane.activity, '#'),
+      (pane.dead, 'X'),
+    ]
+    .into_iter()
+    .filter_map(|(set, flag)| set.then_some(flag))
+    .collect()
+  }
+
+  fn git_branch(path: &Path) -> Option<String> {
+    let git = path
+      .ancestors()
+      .map(|directory| directory.join(".git"))
+      .find(|git| git.exists())?;
+
+    let git = if git.is_file() {
+      let contents = fs::read_to_string(&git).ok()?;
+      git
+        .parent()?
+        .join(contents.trim().strip_prefix("gitdir:")?.trim())
+    } else {
+      git
+    };
+
+    let head = fs::read_to_string(git.join("HEAD")).ok()?;
+
+    let head = head.trim();
+
+    Some(match head.strip_prefix("ref: ") {
+      Some(reference) => reference
+        .strip_prefix("refs/heads/")
+        .unwrap_or(reference)
+        .to_string(),
+      None => head.chars().take(7).collect(),
+    })
+  }
+
+  pub(crate) fn render(&self, pane: &Pane, idle: Duration) -> String {
+    let Some(segments) = &self.segments else {
+      return pane.title();
+    };
+
+    let mut title = String::new();
+
+    let mut gap = false;
+
+    for segment in segments {
+      match segment {
+        Segment::Literal(text) => {
+          if gap && (title.is_empty() || title.ends_with(char::is_whitespace)) {
+            title.push_str(text.trim_start());
+          } else {
+            title.push_str(text);
+          }
+
+          gap = false;
+        }
+        Segment::Placeholder(name) => {
+          let value = self.value(pane, name, idle);
+
+          gap = value.is_empty();
+
+          title.push_str(&value);
+        }
+      }
+    }
+
+    if gap {
+      title.truncate(title.trim_end().len());
+    }
+
+    title
+  }
+
+  fn segments(template: &str) -> Result<Vec<Segment>> {
+    let mut segments = Vec::new();
+
+    let mut literal = String::new();
+
+    let mut chars = template.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+      match ch {
+        '{' if chars.peek() == Some(&'{') => {
+          chars.next();
+          literal.push('{');
+        }
+        '}' if chars.peek() == Some(&'}') => {
+          chars.next();
+          literal.push('}');
+        }
+        '{' => {
+          let mut name = String::new();
+
+          loop {
+            match chars.next() {
+              Some('}') => break,
+              Some(ch) => name.push(ch),
+              None => bail!("unclosed placeholder in title template"),
+            }
+          }
+
+          let name = name.trim().to_string();
+
+          if !Pane::FIELDS.contains(&name.as_str())
+            && !Self::PLACEHOLDERS.contains(&name.as_str())
+          {
+            bail!("unknown title placeholder `{{{name}}}`");
+          }
+
+          if !literal.is_empty() {
+            segments.push(Segment::Literal(mem::take(&mut literal)));
+          }
+
+          segments.push(Segment::Placeholder(name));
+        }
+        '}' => bail!("unmatched `}}` in title template, use `}}}}`"),
+        _ => literal.push(ch),
+      }
+    }
+
+    if !literal.is_empty() {
+      segments.push(Segment::Literal(literal));
+    }
+
+    Ok(segments)
+  }
+
+  fn short_path(path: &str) -> String {
+    env::var("HOME")
+      .ok()
+      .filter(|home| !home.is_empty())
+      .and_then(|home| {
+        let rest = Path::new(path).strip_prefix(&home).ok()?;
+
+        Some(if rest.as_os_str().is_empty() {
+          "~".to_string()
+        } else {
+          format!("~/{}", rest.display())
+        })
+      })
+      .unwrap_or_else(|| path.to_string())
+  }
+
+  pub(crate) fn truncate(text: &str, width: usize) -> String {
+    if text
+      .chars()
+      .map(|ch| ch.width().unwrap_or(0))
+      .sum::<usize>()
+      <= width
+    {
+      return text.to_string();
+    }
+
+    let mut truncated = String::new();
+
+    let mut used = 0;
+
+    for ch in text.chars() {
+      let ch_width = ch.width().unwrap_or(0);
+
+      if used + ch_width + 1 > width {
+        break;
+      }
+
+      truncated.push(ch);
+      used += ch_width;
+    }
+
+    if width > 0 {
+      truncated.push('…');
+    }
+
+    truncated
+  }
+
+  fn value(&self, pane: &Pane, name: &str, idle: Duration) -> String {
+    match name {
+      "branch" => self.branch(&pane.path).unwrap_or_default(),
+      "default" => pane.title(),
+      "flags" => Self::flags(pane),
+      "idle" => ListView::format_duration(idle),
+      "short_path" => Self::short_path(&pane.path),
+      _ => pane.field(name).unwrap_or_default(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn format(template: &str) -> TitleFormat {
+    TitleFormat {
+      segments: Some(TitleFormat::segments(template).unwrap()),
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn render_substitutes_placeholders() {
+    let pane = Pane {
+      active: true,
+      command: "claude".into(),
+      pane_title: "Fixing tests".into(),
+      session: "work".into(),
+      window_name: "api".into(),
+      zoomed: true,
+      ..Default::default()
+    };
+
+    assert_eq!(
+      format("{{{window_name}}} {title} [{flags}] {branch} {idle}")
+        .render(&pane, Duration::from_secs(90)),
+      "{api} Fixing tests [*Z] 1m"
+    );
+
+    assert_eq!(
+      TitleFormat::default().render(&pane, Duration::ZERO),
+      "work:0.0 (claude)"
+    );
+  }
+
+  #[test]
+  fn render_trims_only_gaps_left_by_empty_placeholders() {
+    let pane = Pane {
+      command: "claude".into(),
+      ..Default::default()
+    };
+
+    assert_eq!(
+      format("{branch} {command}  |  {branch}").render(&pane, Duration::ZERO),
+      "claude  |"
+    );
+
+    assert_eq!(
+      format("[ {command} ]").render(&pane, Duration::ZERO),
+      "[ claude ]"
+    );
+  }
+
+  #[test]
+  fn template_rejects_unknown_and_unclosed_placeholders() {
+    let parse = |template: &str| {
+      toml::from_str::<TitleFormat>(&format!("template = {template:?}"))
+    };
+
+    assert!(parse("{location} ({command})").is_ok());
+    assert!(parse("{colour}").is_err());
+    assert!(parse("{location").is_err());
+    assert!(parse("location}").is_err());
+  }
+
+  #[test]
+  fn truncate_adds_ellipsis_when_too_wide() {
+    assert_eq!(TitleFormat::truncate("session:0.1", 20), "session:0.1");
+    assert_eq!(TitleFormat::truncate("session:0.1", 8), "session…");
+    assert_eq!(TitleFormat::truncate("日本語", 4), "日…");
+    assert_eq!(TitleFormat::truncate("session", 0), "");
+  }
+
+  #[test]
+  fn git_branch_reads_head() {
+    let root = env::temp_dir().join(format!("spymux-title-{}", process::id()));
+
+    fs::create_dir_all(root.join(".git")).unwrap();
+    fs::create_dir_all(root.join("src")).unwrap();
+    fs::write(root.join(".git/HEAD"), "ref: refs/heads/feature/x\n").unwrap();
+
+    assert_eq!(
+      TitleFormat::git_branch(&root.join("src")).as_deref(),
+      Some("feature/x")
+    );
+
+    fs::write(root.join(".git/HEAD"), "0123456789abcdef\n").unwrap();
+
+    assert_eq!(TitleFormat::git_branch(&root).as_deref(), Some("0123456"));
+
+    fs::remove_dir_all(root).unwrap();
+  }
+}
@@ -2,14 +2,131 @@ use super::*;
 
 #[derive(Debug, Default)]
 pub(crate) struct Tmux {
+  excluded_commands: Vec<String>,
   pub(crate) excluded_pane_ids: Vec<String>,
+  pub(crate) failures: Vec<String>,
+  filters: Vec<PaneFilter>,
+  idle_shells: Vec<String>,
   pub(crate) include_escape_codes: bool,
+  pub(crate) included_windows: Option<BTreeSet<(String, String)>>,
   pub(crate) panes: Vec<Pane>,
+  processes: ProcessMonitor,
+  servers: Vec<Server>,
+  sort: Option<PaneSort>,
+  virtual_panes: VirtualPanes,
 }
 
 impl Tmux {
+  #[cfg(unix)]
+  pub(crate) fn attach(pane: &Pane) -> Result {
+    use std::os::unix::process::CommandExt;
+
+    let error = Command::new("tmux")
+      .args(pane.server.arguments())
+      .args(["attach-session", "-t", pane.target()])
+      .exec();
+
+    Err(error)
+      .with_context(|| format!("failed to attach to {}", pane.location()))
+  }
+
+  #[cfg(not(unix))]
+  pub(crate) fn attach(pane: &Pane) -> Result {
+    let status = Command::new("tmux")
+      .args(pane.server.arguments())
+      .args(["attach-session", "-t", pane.target()])
+      .status()
+      .with_context(|| format!("failed to attach to {}", pane.location()))?;
+
+    process::exit(status.code().unwrap_or(1))
+  }
+
+  pub(crate) fn break_pane(pane: &Pane) -> Result {
+    Self::break_pane_with_runner(pane, &TmuxCommandRunner)
+  }
+
+  fn break_pane_with_runner(pane: &Pane, runner: &dyn CommandRunner) -> Result {
+    let output = runner.run(
+      &pane.server,
+      &[
+        "break-pane",
+        "-d",
+        "-s",
+        pane.target(),
+        "-t",
+        &format!("{}:", pane.session),
+      ],
+    )?;
+
+    if !output.status.success() {
+      bail!("failed to break pane {}", pane.descriptor());
+    }
+
+    Ok(())
+  }
+
   pub(crate) fn capture(&mut self) -> Result {
-    self.capture_with_runner(&TmuxCommandRunner)
+    self.capture_with_runner(&TmuxCommandRunner)?;
+
+    if self.included_windows.is_none() {
+      let filters = &self.filters;
+
+      self.panes.extend(
+        self
+          .virtual_panes
+          .refresh()
+          .into_iter()
+          .filter(|pane| filters.iter().all(|filter| filter.matches(pane))),
+      );
+    }
+
+    Ok(())
+  }
+
+  pub(crate) fn capture_history(
+    pane: &Pane,
+    scrollback: Option<usize>,
+    include_escape_codes: bool,
+  ) -> Result<String> {
+    Self::capture_history_with_runner(
+      pane,
+      scrollback,
+      include_escape_codes,
+      &TmuxCommandRunner,
+    )
+  }
+
+  fn capture_history_with_runner(
+    pane: &Pane,
+    scrollback: Option<usize>,
+    include_escape_codes: bool,
+    runner: &dyn CommandRunner,
+  ) -> Result<String> {
+    let (descriptor, start) = (
+      pane.descriptor(),
+      scrollback.map_or_else(|| "-".to_string(), |lines| format!("-{lines}")),
+    );
+
+    let mut capture_cmd = vec![
+      "capture-pane",
+      "-t",
+      descriptor.as_str(),
+      "-p",
+      "-S",
+      start.as_str(),
+    ];
+
+    if include_escape_codes {
+      capture_cmd.push("-e");
+    }
+
+    let output = runner.run(&pane.server, &capture_cmd)?;
+
+    if !output.status.success() {
+      bail!("failed to capture pane history for {descriptor}");
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
   }
 
   fn capture_pane(
@@ -25,7 +142,7 @@ impl Tmux {
       capture_cmd.push("-e");
     }
 
-    let content_output = runner.run(&capture_cmd)?;
+    let content_output = runner.run(&pane.server, &capture_cmd)?;
 
     if !content_output.status.success() {
       bail!("failed to capture pane output");
@@ -39,12 +156,42 @@ impl Tmux {
   fn capture_with_runner(&mut self, runner: &dyn CommandRunner) -> Result {
     let excluded = &self.excluded_pane_ids;
 
-    self.panes = Self::list_panes(runner)?
+    let included = self.included_windows.as_ref();
+
+    let (panes, failures) =
+      Self::list_all_panes_with_runner(&self.servers, runner)?;
+
+    self.failures = failures;
+
+    let mut panes = panes
       .into_iter()
       .filter(|pane| !excluded.contains(&pane.id))
+      .filter(|pane| {
+        !self.excluded_commands.iter().any(|command| {
+          pane.command.trim().eq_ignore_ascii_case(command.trim())
+        })
+      })
+      .filter(|pane| {
+        included.is_none_or(|windows| {
+          windows.contains(&(pane.session.clone(), pane.window_name.clone()))
+        })
+      })
+      .collect::<Vec<Pane>>();
+
+    self.processes.sample(&mut panes);
+
+    panes.retain(|pane| !self.is_idle_shell(pane));
+
+    self.panes = panes
+      .into_iter()
+      .filter(|pane| self.filters.iter().all(|filter| filter.matches(pane)))
       .map(|pane| self.capture_pane(pane, runner))
       .collect::<Result<Vec<_>>>()?;
 
+    if let Some(sort) = &self.sort {
+      sort.sort(&mut self.panes);
+    }
+
     Ok(())
   }
 
@@ -58,61 +205,379 @@ impl Tmux {
   }
 
   fn focus_pane_with_runner(pane: &Pane, runner: &dyn CommandRunner) -> Result {
+    let (session, window_index) = Self::focus_session(pane, runner);
+
     Self::select_window_with_runner(
-      &format!("{}:{}", pane.session, pane.window_index),
+      &pane.server,
+      &format!("{session}:{window_index}"),
       runner,
     )?;
 
-    Self::select_pane_with_runner(&pane.id, runner)
+    Self::select_pane_with_runner(&pane.server, pane.target(), runner)
+  }
+
+  fn focus_session(pane: &Pane, runner: &dyn CommandRunner) -> (String, usize) {
+    if pane.sessions.len() > 1 {
+      let client_session = runner
+        .run(
+          &pane.server,
+          &["display-message", "-p", "#{client_session}"],
+        )
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| {
+          String::from_utf8_lossy(&output.stdout).trim().to_string()
+        });
+
+      if let Some((session, window_index)) = client_session.and_then(|name| {
+        pane
+          .sessions
+          .get_key_value(&name)
+          .map(|(k, v)| (k.clone(), *v))
+      }) {
+        return (session, window_index);
+      }
+    }
+
+    (pane.session.clone(), pane.window_index)
   }
 
-  fn list_panes(runner: &dyn CommandRunner) -> Result<Vec<Pane>> {
-    let output = runner.run(&["list-panes", "-a", "-F", Pane::format()])?;
+  fn is_idle_shell(&self, pane: &Pane) -> bool {
+    self
+      .idle_shells
+      .iter()
+      .any(|shell| pane.command.trim().eq_ignore_ascii_case(shell.trim()))
+      && pane
+        .process
+        .as_ref()
+        .is_some_and(|process| process.pid == pane.pid && process.children == 0)
+  }
+
+  pub(crate) fn kill_pane(pane: &Pane) -> Result {
+    Self::kill_pane_with_runner(pane, &TmuxCommandRunner)
+  }
+
+  fn kill_pane_with_runner(pane: &Pane, runner: &dyn CommandRunner) -> Result {
+    let output =
+      runner.run(&pane.server, &["kill-pane", "-t", pane.target()])?;
+
+    if !output.status.success() {
+      bail!("failed to kill pane {}", pane.descriptor());
+    }
+
+    Ok(())
+  }
+
+  pub(crate) fn kill_window(pane: &Pane) -> Result {
+    Self::kill_window_with_runner(pane, &TmuxCommandRunner)
+  }
+
+  fn kill_window_with_runner(
+    pane: &Pane,
+    runner: &dyn CommandRunner,
+  ) -> Result {
+    let output =
+      runner.run(&pane.server, &["kill-window", "-t", pane.target()])?;
 
     if !output.status.success() {
-      bail!("failed to list tmux panes");
+      bail!(
+        "failed to kill window {}:{}",
+        pane.session,
+        pane.window_index
+      );
+    }
+
+    Ok(())
+  }
+
+  pub(crate) fn list_all_panes(servers: &[Server]) -> Result<Vec<Pane>> {
+    let (panes, failures) =
+      Self::list_all_panes_with_runner(servers, &TmuxCommandRunner)?;
+
+    Self::warn(&failures);
+
+    Ok(panes)
+  }
+
+  fn list_all_panes_with_runner(
+    servers: &[Server],
+    runner: &dyn CommandRunner,
+  ) -> Result<(Vec<Pane>, Vec<String>)> {
+    let mut panes = Vec::new();
+
+    let mut failures = Vec::new();
+
+    let mut listed = false;
+
+    for server in servers {
+      match Self::list_panes(server, runner) {
+        Ok(Some(server_panes)) => {
+          listed = true;
+          panes.extend(server_panes);
+        }
+        Ok(None) => {
+          listed = true;
+
+          if let Some(label) = server.label() {
+            failures.push(format!("tmux server {label} is not running"));
+          }
+        }
+        Err(error) => failures.push(format!("{error:#}")),
+      }
+    }
+
+    if !listed {
+      bail!("{}", failures.join("; "));
+    }
+
+    Ok((panes, failures))
+  }
+
+  fn list_panes(
+    server: &Server,
+    runner: &dyn CommandRunner,
+  ) -> Result<Option<Vec<Pane>>> {
+    let output =
+      runner.run(server, &["list-panes", "-a", "-F", Pane::format()])?;
+
+    if !output.status.success() {
+      let stderr = String::from_utf8_lossy(&output.stderr);
+
+      if Self::server_not_running(&stderr) {
+        return Ok(None);
+      }
+
+      match server.label() {
+        Some(label) => bail!("failed to list panes of tmux server {label}"),
+        None => bail!("failed to list tmux panes"),
+      }
     }
 
     let pane_list = String::from_utf8(output.stdout)?;
 
-    pane_list
-      .lines()
-      .filter(|line| !line.is_empty())
-      .map(|line| serde_json::from_str(line).map_err(Into::into))
-      .collect()
+    let mut panes = Vec::<Pane>::new();
+
+    let mut indices = HashMap::<String, usize>::new();
+
+    for line in pane_list.lines().filter(|line| !line.is_empty()) {
+      let mut pane = Pane::parse(line)?;
+
+      pane.id = server.qualify(&pane.id);
+
+      if let Some(existing) = indices
+        .get(&pane.id)
+        .and_then(|index| panes.get_mut(*index))
+      {
+        if existing.sessions.is_empty() {
+          existing
+            .sessions
+            .insert(existing.session.clone(), existing.window_index);
+        }
+
+        existing.sessions.insert(pane.session, pane.window_index);
+
+        continue;
+      }
+
+      pane.server = server.clone();
+
+      indices.insert(pane.id.clone(), panes.len());
+
+      panes.push(pane);
+    }
+
+    Ok(Some(panes))
   }
 
-  pub(crate) fn list_panes_by_command(command: &str) -> Result<Vec<Pane>> {
-    Self::list_panes_by_command_with_runner(command, &TmuxCommandRunner)
+  pub(crate) fn list_panes_by_command(
+    servers: &[Server],
+    command: &str,
+  ) -> Result<Vec<Pane>> {
+    let (panes, failures) = Self::list_panes_by_command_with_runner(
+      servers,
+      command,
+      &TmuxCommandRunner,
+    )?;
+
+    Self::warn(&failures);
+
+    Ok(panes)
   }
 
   fn list_panes_by_command_with_runner(
+    servers: &[Server],
     command: &str,
     runner: &dyn CommandRunner,
-  ) -> Result<Vec<Pane>> {
+  ) -> Result<(Vec<Pane>, Vec<String>)> {
     let command = command.trim();
 
-    Ok(
-      Self::list_panes(runner)?
+    let (panes, failures) = Self::list_all_panes_with_runner(servers, runner)?;
+
+    Ok((
+      panes
         .into_iter()
         .filter(|pane| pane.command.trim().eq_ignore_ascii_case(command))
         .collect(),
-    )
+      failures,
+    ))
   }
 
   pub(crate) fn new(config: Config) -> Self {
     Self {
+      excluded_commands: config.excluded_commands,
       excluded_pane_ids: Vec::new(),
+      failures: Vec::new(),
+      filters: config.filters,
+      idle_shells: config.idle_shells,
       include_escape_codes: config.color_output,
+      included_windows: config.workspace_windows,
       panes: Vec::new(),
+      processes: ProcessMonitor::default(),
+      servers: config.servers,
+      sort: config.sort,
+      virtual_panes: VirtualPanes::new(config.virtual_panes),
+    }
+  }
+
+  pub(crate) fn refresh_pane(&self, pane_id: &str) -> Result<Option<Pane>> {
+    self.refresh_pane_with_runner(pane_id, &TmuxCommandRunner)
+  }
+
+  fn refresh_pane_with_runner(
+    &self,
+    pane_id: &str,
+    runner: &dyn CommandRunner,
+  ) -> Result<Option<Pane>> {
+    for server in &self.servers {
+      if let Some(mut pane) = Self::list_panes(server, runner)?
+        .into_iter()
+        .flatten()
+        .find(|pane| pane.id == pane_id)
+      {
+        pane.process = self
+          .panes
+          .iter()
+          .find(|existing| existing.id == pane_id)
+          .and_then(|existing| existing.process.clone());
+
+        return self.capture_pane(pane, runner).map(Some);
+      }
+    }
+
+    Ok(None)
+  }
+
+  pub(crate) fn rename_window(pane: &Pane, name: &str) -> Result {
+    Self::rename_window_with_runner(pane, name, &TmuxCommandRunner)
+  }
+
+  fn rename_window_with_runner(
+    pane: &Pane,
+    name: &str,
+    runner: &dyn CommandRunner,
+  ) -> Result {
+    let output = runner.run(
+      &pane.server,
+      &["rename-window", "-t", pane.target(), "--", name],
+    )?;
+
+    if !output.status.success() {
+      bail!("failed to rename window of pane {}", pane.descriptor());
     }
+
+    Ok(())
+  }
+
+  pub(crate) fn respawn_pane(pane: &Pane) -> Result {
+    Self::respawn_pane_with_runner(pane, &TmuxCommandRunner)
+  }
+
+  fn respawn_pane_with_runner(
+    pane: &Pane,
+    runner: &dyn CommandRunner,
+  ) -> Result {
+    let output =
+      runner.run(&pane.server, &["respawn-pane", "-k", "-t", pane.target()])?;
+
+    if !output.status.success() {
+      bail!("failed to respawn pane {}", pane.descriptor());
+    }
+
+    Ok(())
+  }
+
+  pub(crate) fn scroll_to_line(
+    pane: &Pane,
+    lines_from_bottom: usize,
+  ) -> Result {
+    Self::scroll_to_line_with_runner(
+      pane,
+      lines_from_bottom,
+      &TmuxCommandRunner,
+    )
+  }
+
+  fn scroll_to_line_with_runner(
+    pane: &Pane,
+    lines_from_bottom: usize,
+    runner: &dyn CommandRunner,
+  ) -> Result {
+    let output =
+      runner.run(&pane.server, &["copy-mode", "-t", pane.target()])?;
+
+    if !output.status.success() {
+      bail!("failed to enter copy mode");
+    }
+
+    let output = runner.run(
+      &pane.server,
+      &[
+        "send-keys",
+        "-t",
+        pane.target(),
+        "-X",
+        "goto-line",
+        &lines_from_bottom.to_string(),
+      ],
+    )?;
+
+    if !output.status.success() {
+      bail!("failed to scroll to line");
+    }
+
+    Ok(())
+  }
+
+  pub(crate) fn select_layout(
+    server: &Server,
+    pane_id: &str,
+    layout: &str,
+  ) -> Result {
+    Self::select_layout_with_runner(server, pane_id, layout, &TmuxCommandRunner)
+  }
+
+  fn select_layout_with_runner(
+    server: &Server,
+    pane_id: &str,
+    layout: &str,
+    runner: &dyn CommandRunner,
+  ) -> Result {
+    let output =
+      runner.run(server, &["select-layout", "-t", pane_id, layout])?;
+
+    if !output.status.success() {
+      bail!("failed to select layout {layout}");
+    }
+
+    Ok(())
   }
 
   fn select_pane_with_runner(
+    server: &Server,
     pane_id: &str,
     runner: &dyn CommandRunner,
   ) -> Result {
-    let output = runner.run(&["select-pane", "-t", pane_id])?;
+    let output = runner.run(server, &["select-pane", "-t", pane_id])?;
 
     if !output.status.success() {
       bail!("failed to select tmux pane");
@@ -122,10 +587,11 @@ impl Tmux {
   }
 
   fn select_window_with_runner(
+    server: &Server,
     target: &str,
     runner: &dyn CommandRunner,
   ) -> Result {
-    let output = runner.run(&["select-window", "-t", target])?;
+    let output = runner.run(server, &["select-window", "-t", target])?;
 
     if !output.status.success() {
       bail!("failed to select tmux window");
@@ -133,6 +599,107 @@ impl Tmux {
 
     Ok(())
   }
+
+  fn server_not_running(stderr: &str) -> bool {
+    stderr.contains("no server running")
+      || (stderr.contains("error connecting to")
+        && (stderr.contains("No such file or directory")
+          || stderr.contains("Connection refused")))
+  }
+
+  pub(crate) fn spawn(
+    server: &Server,
+    request: &SpawnRequest,
+  ) -> Result<String> {
+    Self::spawn_with_runner(server, request, &TmuxCommandRunner)
+  }
+
+  fn spawn_with_runner(
+    server: &Server,
+    request: &SpawnRequest,
+    runner: &dyn CommandRunner,
+  ) -> Result<String> {
+    let arguments = request.arguments();
+
+    let output = runner.run(
+      server,
+      &arguments.iter().map(String::as_str).collect::<Vec<&str>>(),
+    )?;
+
+    if !output.status.success() {
+      bail!(
+        "failed to spawn pane: {}",
+        String::from_utf8_lossy(&output.stderr).trim()
+      );
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+  }
+
+  pub(crate) fn swap_panes(source: &Pane, target: &Pane) -> Result {
+    Self::swap_panes_with_runner(source, target, &TmuxCommandRunner)
+  }
+
+  fn swap_panes_with_runner(
+    source: &Pane,
+    target: &Pane,
+    runner: &dyn CommandRunner,
+  ) -> Result {
+    if source.server != target.server {
+      bail!("cannot swap panes on different tmux servers");
+    }
+
+    let output = runner.run(
+      &source.server,
+      &[
+        "swap-pane",
+        "-d",
+        "-s",
+        source.target(),
+        "-t",
+        target.target(),
+      ],
+    )?;
+
+    if !output.status.success() {
+      bail!(
+        "failed to swap pane {} with {}",
+        source.descriptor(),
+        target.descriptor()
+      );
+    }
+
+    Ok(())
+  }
+
+  pub(crate) fn switch_client(pane: &Pane) -> Result {
+    Self::switch_client_with_runner(pane, &TmuxCommandRunner)
+  }
+
+  fn switch_client_with_runner(
+    pane: &Pane,
+    runner: &dyn CommandRunner,
+  ) -> Result {
+    let target = if pane.sessions.len() > 1 {
+      Self::focus_session(pane, runner).0
+    } else {
+      pane.target().to_string()
+    };
+
+    let output = runner.run(&pane.server, &["switch-client", "-t", &target])?;
+
+    if !output.status.success() {
+      bail!("failed to switch client to {}", pane.location());
+    }
+
+    Ok(())
+  }
+
+  fn warn(failures: &[String]) {
+    for failure in failures {
+      eprintln!("warning: {failure}");
+    }
+  }
 }
 
 #[cfg(test)]
@@ -146,7 +713,10 @@ mod tests {
   struct MockCommandRunner {
     capture_outputs: BTreeMap<String, String>,
     capture_successes: BTreeMap<String, bool>,
+    client_session: Option<String>,
+    commands: RefCell<Vec<String>>,
     list_panes_output: String,
+    list_panes_stderr: BTreeMap<Server, String>,
     list_panes_success: bool,
     select_pane_success: bool,
     select_window_success: bool,
@@ -159,7 +729,10 @@ mod tests {
       Self {
         capture_outputs: BTreeMap::new(),
         capture_successes: BTreeMap::new(),
+        client_session: None,
+        commands: RefCell::new(Vec::new()),
         list_panes_output: String::new(),
+        list_panes_stderr: BTreeMap::new(),
         list_panes_success: true,
         select_pane_success: true,
         select_window_success: true,
@@ -170,12 +743,29 @@ mod tests {
   }
 
   impl CommandRunner for MockCommandRunner {
-    fn run(&self, arguments: &[&str]) -> Result<Output> {
+    fn run(&self, server: &Server, arguments: &[&str]) -> Result<Output> {
+      self.commands.borrow_mut().push(
+        server
+          .arguments()
+          .iter()
+          .map(String::as_str)
+          .chain(arguments.iter().copied())
+          .collect::<Vec<&str>>()
+          .join(" "),
+      );
+
       match arguments[0] {
-        "list-panes" => Ok(Output {
-          status: exit_status(self.list_panes_success),
-          stdout: self.list_panes_output.as_bytes().to_vec(),
-          stderr: vec![],
+        "list-panes" => Ok(match self.list_panes_stderr.get(server) {
+          Some(stderr) => Output {
+            status: exit_status(false),
+            stdout: vec![],
+            stderr: stderr.as_bytes().to_vec(),
+          },
+          None => Output {
+            status: exit_status(self.list_panes_success),
+            stdout: self.list_panes_output.as_bytes().to_vec(),
+            stderr: vec![],
+          },
         }),
         "capture-pane" => {
           let pane_id = arguments[2];
@@ -216,12 +806,37 @@ mod tests {
             stderr: vec![],
           })
         }
+        "display-message" => Ok(Output {
+          status: exit_status(self.client_session.is_some()),
+          stdout: self
+            .client_session
+            .clone()
+            .map(|session| format!("{session}\n").into_bytes())
+            .unwrap_or_default(),
+          stderr: vec![],
+        }),
+        "new-session" | "new-window" | "split-window" => Ok(Output {
+          status: exit_status(true),
+          stdout: b"%42\n".to_vec(),
+          stderr: vec![],
+        }),
+        "break-pane" | "copy-mode" | "kill-pane" | "kill-window"
+        | "rename-window" | "respawn-pane" | "select-layout" | "send-keys"
+        | "swap-pane" | "switch-client" => Ok(Output {
+          status: exit_status(true),
+          stdout: vec![],
+          stderr: vec![],
+        }),
         _ => bail!("unexpected command"),
       }
     }
   }
 
   impl MockCommandRunner {
+    fn commands(&self) -> Vec<String> {
+      self.commands.borrow().clone()
+    }
+
     fn selected_panes(&self) -> Vec<String> {
       self.selected_panes.borrow().clone()
     }
@@ -250,6 +865,20 @@ mod tests {
     .to_string()
   }
 
+  fn window_pane(session: &str, window_name: &str, id: &str) -> String {
+    format!(
+      "{}\t{window_name}\t",
+      json!({
+        "command": "",
+        "id": id,
+        "index": 0,
+        "path": "",
+        "session": session,
+        "window_index": 0,
+      })
+    )
+  }
+
   #[cfg(unix)]
   fn exit_status(success: bool) -> ExitStatus {
     use std::os::unix::process::ExitStatusExt;
@@ -324,6 +953,7 @@ mod tests {
         path: String::new(),
         session: "session1".to_string(),
         window_index: 0,
+        ..Default::default()
       }]
     );
   }
@@ -362,6 +992,7 @@ mod tests {
           path: String::new(),
           session: "session1".to_string(),
           window_index: 0,
+          ..Default::default()
         },
         Pane {
           command: String::new(),
@@ -371,6 +1002,7 @@ mod tests {
           path: String::new(),
           session: "session1".to_string(),
           window_index: 0,
+          ..Default::default()
         },
         Pane {
           command: String::new(),
@@ -380,11 +1012,103 @@ mod tests {
           path: String::new(),
           session: "session2".to_string(),
           window_index: 1,
+          ..Default::default()
         },
       ]
     );
   }
 
+  #[test]
+  fn only_shells_without_a_foreground_job_are_idle() {
+    let tmux = Tmux::new(Config {
+      idle_shells: vec!["bash".into()],
+      ..Default::default()
+    });
+
+    let shell = |command: &str, foreground: Option<(u32, usize)>| Pane {
+      command: command.into(),
+      pid: 100,
+      process: foreground.map(|(pid, children)| ProcessInfo {
+        children,
+        pid,
+        ..Default::default()
+      }),
+      ..Default::default()
+    };
+
+    assert!(tmux.is_idle_shell(&shell("bash", Some((100, 0)))));
+    assert!(!tmux.is_idle_shell(&shell("bash", Some((100, 1)))));
+    assert!(!tmux.is_idle_shell(&shell("bash", Some((200, 0)))));
+    assert!(!tmux.is_idle_shell(&shell("bash", None)));
+    assert!(!tmux.is_idle_shell(&shell("vim", Some((100, 0)))));
+  }
+
+  #[test]
+  fn capture_skips_excluded_commands() {
+    let mut capture_outputs = BTreeMap::new();
+
+    capture_outputs.insert("session1:0.0".to_string(), "Pane 1\n".to_string());
+    capture_outputs.insert("session1:0.1".to_string(), "Pane 2\n".to_string());
+    capture_outputs.insert("session1:0.2".to_string(), "Pane 3\n".to_string());
+
+    let runner = MockCommandRunner {
+      capture_outputs,
+      list_panes_output: format!(
+        "{}\n{}\n{}\n",
+        pane("session1", 0, 0, "%0", "claude", ""),
+        pane("session1", 0, 1, "%1", "spymux", ""),
+        pane("session1", 0, 2, "%2", " Zsh ", "")
+      ),
+      ..Default::default()
+    };
+
+    let mut tmux = Tmux::new(Config {
+      excluded_commands: vec!["spymux".into(), "zsh".into()],
+      ..Default::default()
+    });
+
+    tmux.capture_with_runner(&runner).unwrap();
+
+    assert_eq!(
+      tmux
+        .panes
+        .iter()
+        .map(|pane| pane.id.as_str())
+        .collect::<Vec<_>>(),
+      ["%0"]
+    );
+  }
+
+  #[test]
+  fn capture_applies_filters_and_sort() {
+    let runner = MockCommandRunner {
+      list_panes_output: format!(
+        "{}\n{}\n{}\n",
+        pane("session1", 0, 0, "%0", "bash", "/b"),
+        pane("session1", 0, 1, "%1", "claude", "/c"),
+        pane("session1", 0, 2, "%2", "claude", "/a")
+      ),
+      ..Default::default()
+    };
+
+    let mut tmux = Tmux::new(Config {
+      filters: vec![PaneFilter::parse("command=claude").unwrap()],
+      sort: Some(PaneSort::parse("path").unwrap()),
+      ..Default::default()
+    });
+
+    tmux.capture_with_runner(&runner).unwrap();
+
+    assert_eq!(
+      tmux
+        .panes
+        .iter()
+        .map(|pane| pane.id.as_str())
+        .collect::<Vec<_>>(),
+      ["%2", "%1"]
+    );
+  }
+
   #[test]
   fn capture_skips_excluded_panes() {
     let mut capture_outputs = BTreeMap::new();
@@ -417,10 +1141,42 @@ mod tests {
         path: String::new(),
         session: "session1".to_string(),
         window_index: 0,
+        ..Default::default()
       }]
     );
   }
 
+  #[test]
+  fn capture_keeps_only_included_windows() {
+    let runner = MockCommandRunner {
+      list_panes_output: format!(
+        "{}\n{}\n",
+        window_pane("agents", "api", "%0"),
+        window_pane("agents", "scratch", "%1")
+      ),
+      ..Default::default()
+    };
+
+    let mut tmux = Tmux::new(Config {
+      workspace_windows: Some(BTreeSet::from([(
+        "agents".to_string(),
+        "api".to_string(),
+      )])),
+      ..Default::default()
+    });
+
+    tmux.capture_with_runner(&runner).unwrap();
+
+    assert_eq!(
+      tmux
+        .panes
+        .iter()
+        .map(|pane| pane.id.as_str())
+        .collect::<Vec<_>>(),
+      ["%0"]
+    );
+  }
+
   #[test]
   fn parse_pane_with_different_indices() {
     let mut capture_outputs = BTreeMap::new();
@@ -451,6 +1207,7 @@ mod tests {
         path: String::new(),
         session: "mysession".to_string(),
         window_index: 5,
+        ..Default::default()
       }]
     );
   }
@@ -484,6 +1241,7 @@ mod tests {
         path: String::new(),
         session: "session1".to_string(),
         window_index: 0,
+        ..Default::default()
       }]
     );
   }
@@ -517,6 +1275,7 @@ mod tests {
         path: String::new(),
         session: "session1".to_string(),
         window_index: 0,
+        ..Default::default()
       }]
     );
   }
@@ -533,6 +1292,7 @@ mod tests {
           path: String::new(),
           session: "session1".to_string(),
           window_index: 0,
+          ..Default::default()
         },
         Pane {
           command: String::new(),
@@ -542,6 +1302,7 @@ mod tests {
           path: String::new(),
           session: "session1".to_string(),
           window_index: 0,
+          ..Default::default()
         },
       ],
       ..Default::default()
@@ -559,6 +1320,7 @@ mod tests {
         path: String::new(),
         session: "session1".to_string(),
         window_index: 0,
+        ..Default::default()
       }]
     );
   }
@@ -567,7 +1329,7 @@ mod tests {
   fn select_pane_with_runner_invokes_tmux() {
     let runner = MockCommandRunner::default();
 
-    Tmux::select_pane_with_runner("%42", &runner).unwrap();
+    Tmux::select_pane_with_runner(&Server::Default, "%42", &runner).unwrap();
 
     assert_eq!(runner.selected_panes(), vec!["%42".to_string()]);
   }
@@ -580,7 +1342,7 @@ mod tests {
     };
 
     assert_eq!(
-      Tmux::select_pane_with_runner("%1", &runner)
+      Tmux::select_pane_with_runner(&Server::Default, "%1", &runner)
         .unwrap_err()
         .to_string(),
       "failed to select tmux pane"
@@ -599,6 +1361,7 @@ mod tests {
       path: String::new(),
       session: "mysession".to_string(),
       window_index: 3,
+      ..Default::default()
     };
 
     Tmux::focus_pane_with_runner(&pane, &runner).unwrap();
@@ -607,6 +1370,93 @@ mod tests {
     assert_eq!(runner.selected_panes(), vec!["%12".to_string()]);
   }
 
+  #[test]
+  fn list_panes_accepts_quoted_window_names() {
+    let runner = MockCommandRunner {
+      list_panes_output: format!(
+        "{}\n{}\n",
+        window_pane("agents", r#"say "hi" \ bye"#, "%0"),
+        window_pane("agents", "api", "%1")
+      ),
+      ..Default::default()
+    };
+
+    assert_eq!(
+      Tmux::list_panes(&Server::Default, &runner)
+        .unwrap()
+        .unwrap()
+        .iter()
+        .map(|pane| pane.window_name.as_str())
+        .collect::<Vec<_>>(),
+      [r#"say "hi" \ bye"#, "api"]
+    );
+  }
+
+  #[test]
+  fn list_panes_merges_linked_windows_and_grouped_sessions() {
+    let runner = MockCommandRunner {
+      list_panes_output: format!(
+        "{}\n{}\n{}\n{}\n",
+        pane("main", 0, 0, "%0", "bash", ""),
+        pane("main", 1, 0, "%1", "claude", ""),
+        pane("mirror", 0, 0, "%0", "bash", ""),
+        pane("other", 3, 0, "%1", "claude", "")
+      ),
+      ..Default::default()
+    };
+
+    let panes = Tmux::list_panes(&Server::Default, &runner)
+      .unwrap()
+      .unwrap();
+
+    assert_eq!(
+      panes
+        .iter()
+        .map(|pane| (pane.id.as_str(), pane.session.as_str(), pane.title()))
+        .collect::<Vec<_>>(),
+      [
+        ("%0", "main", "main:0.0 [mirror] (bash)".to_string()),
+        ("%1", "main", "main:1.0 [other] (claude)".to_string()),
+      ]
+    );
+
+    assert_eq!(
+      panes[1].sessions,
+      [("main".to_string(), 1), ("other".to_string(), 3)].into()
+    );
+  }
+
+  #[test]
+  fn focus_pane_prefers_client_session_for_linked_panes() {
+    let runner = MockCommandRunner {
+      client_session: Some("other".into()),
+      ..Default::default()
+    };
+
+    let pane = Pane {
+      id: "%1".into(),
+      session: "main".into(),
+      sessions: [("main".to_string(), 1), ("other".to_string(), 3)].into(),
+      window_index: 1,
+      ..Default::default()
+    };
+
+    Tmux::focus_pane_with_runner(&pane, &runner).unwrap();
+    Tmux::switch_client_with_runner(&pane, &runner).unwrap();
+
+    assert_eq!(runner.selected_windows(), ["other:3"]);
+    assert_eq!(runner.commands().last().unwrap(), "switch-client -t other");
+
+    let runner = MockCommandRunner {
+      client_session: Some("elsewhere".into()),
+      ..Default::default()
+    };
+
+    Tmux::focus_pane_with_runner(&pane, &runner).unwrap();
+
+    assert_eq!(runner.selected_windows(), ["main:1"]);
+  }
+
   #[test]
   fn focus_pane_with_runner_propagates_window_errors() {
     let runner = MockCommandRunner {
@@ -622,6 +1472,7 @@ mod tests {
       path: String::new(),
       session: "mysession".to_string(),
       window_index: 1,
+      ..Default::default()
     };
 
     assert_eq!(
@@ -644,8 +1495,12 @@ mod tests {
       ..Default::default()
     };
 
-    let panes =
-      Tmux::list_panes_by_command_with_runner("spymux", &runner).unwrap();
+    let (panes, _) = Tmux::list_panes_by_command_with_runner(
+      &[Server::Default],
+      "spymux",
+      &runner,
+    )
+    .unwrap();
 
     assert_eq!(panes.len(), 1);
 
@@ -659,6 +1514,7 @@ mod tests {
         path: "/home/project".to_string(),
         session: "session1".to_string(),
         window_index: 0,
+        ..Default::default()
       }]
     );
   }
@@ -674,8 +1530,12 @@ mod tests {
       ..Default::default()
     };
 
-    let panes =
-      Tmux::list_panes_by_command_with_runner("SPYMUx", &runner).unwrap();
+    let (panes, _) = Tmux::list_panes_by_command_with_runner(
+      &[Server::Default],
+      "SPYMUx",
+      &runner,
+    )
+    .unwrap();
 
     assert_eq!(panes.len(), 1);
 
@@ -689,6 +1549,7 @@ mod tests {
         path: "/home/project".to_string(),
         session: "session1".to_string(),
         window_index: 0,
+        ..Default::default()
       }]
     );
   }
@@ -708,6 +1569,38 @@ mod tests {
     );
   }
 
+  #[test]
+  fn stopped_server_lists_no_panes() {
+    for stderr in [
+      "no server running on /tmp/tmux-0/default\n",
+      "error connecting to /tmp/tmux-0/work (No such file or directory)\n",
+    ] {
+      let runner = MockCommandRunner {
+        list_panes_stderr: [(Server::Default, stderr.to_string())].into(),
+        ..Default::default()
+      };
+
+      assert_eq!(
+        Tmux::list_all_panes_with_runner(&[Server::Default], &runner).unwrap(),
+        (Vec::new(), Vec::new())
+      );
+    }
+
+    let runner = MockCommandRunner {
+      list_panes_stderr: [(
+        Server::Default,
+        "error connecting to /tmp/tmux-0/default (Permission denied)\n"
+          .to_string(),
+      )]
+      .into(),
+      ..Default::default()
+    };
+
+    assert!(
+      Tmux::list_all_panes_with_runner(&[Server::Default], &runner).is_err()
+    );
+  }
+
   #[test]
   fn invalid_pane_format_returns_error() {
     let runner = MockCommandRunner {
@@ -797,6 +1690,320 @@ mod tests {
     );
   }
 
+  #[test]
+  fn capture_history_requests_bounded_scrollback() {
+    let mut capture_outputs = BTreeMap::new();
+
+    capture_outputs
+      .insert("session1:0.0".to_string(), "old\nnew\n".to_string());
+
+    let runner = MockCommandRunner {
+      capture_outputs,
+      ..Default::default()
+    };
+
+    let pane = Pane {
+      session: "session1".to_string(),
+      ..Default::default()
+    };
+
+    assert_eq!(
+      Tmux::capture_history_with_runner(&pane, Some(100), false, &runner)
+        .unwrap(),
+      "old\nnew\n"
+    );
+
+    Tmux::capture_history_with_runner(&pane, None, true, &runner).unwrap();
+
+    assert_eq!(
+      runner.commands(),
+      vec![
+        "capture-pane -t session1:0.0 -p -S -100".to_string(),
+        "capture-pane -t session1:0.0 -p -S - -e".to_string(),
+      ]
+    );
+  }
+
+  #[test]
+  fn refresh_pane_captures_only_requested_pane() {
+    let mut capture_outputs = BTreeMap::new();
+
+    capture_outputs.insert("session1:0.1".to_string(), "Pane 2\n".to_string());
+
+    let runner = MockCommandRunner {
+      capture_outputs,
+      list_panes_output: format!(
+        "{}\n{}\n",
+        pane("session1", 0, 0, "%0", "", ""),
+        pane("session1", 0, 1, "%1", "", "")
+      ),
+      ..Default::default()
+    };
+
+    let tmux = Tmux::new(Config::default());
+
+    assert_eq!(
+      tmux
+        .refresh_pane_with_runner("%1", &runner)
+        .unwrap()
+        .map(|pane| pane.content),
+      Some("Pane 2\n".to_string())
+    );
+
+    assert_eq!(tmux.refresh_pane_with_runner("%9", &runner).unwrap(), None);
+
+    assert_eq!(
+      runner.commands(),
+      vec![
+        "list-panes -a -F ".to_string() + Pane::format(),
+        "capture-pane -t session1:0.1 -p -e".to_string(),
+        "list-panes -a -F ".to_string() + Pane::format(),
+      ]
+    );
+  }
+
+  #[test]
+  fn scroll_to_line_enters_copy_mode() {
+    let runner = MockCommandRunner::default();
+
+    let pane = Pane {
+      id: "%4".to_string(),
+      ..Default::default()
+    };
+
+    Tmux::scroll_to_line_with_runner(&pane, 42, &runner).unwrap();
+
+    assert_eq!(
+      runner.commands(),
+      vec![
+        "copy-mode -t %4".to_string(),
+        "send-keys -t %4 -X goto-line 42".to_string(),
+      ]
+    );
+  }
+
+  #[test]
+  fn pane_management_commands_target_pane_ids() {
+    let runner = MockCommandRunner::default();
+
+    let (source, target) = (
+      Pane {
+        id: "%4".to_string(),
+        session: "work".to_string(),
+        ..Default::default()
+      },
+      Pane {
+        id: "%7".to_string(),
+        ..Default::default()
+      },
+    );
+
+    Tmux::kill_pane_with_runner(&source, &runner).unwrap();
+    Tmux::respawn_pane_with_runner(&source, &runner).unwrap();
+    Tmux::rename_window_with_runner(&source, "agents", &runner).unwrap();
+    Tmux::break_pane_with_runner(&source, &runner).unwrap();
+    Tmux::swap_panes_with_runner(&source, &target, &runner).unwrap();
+    Tmux::kill_window_with_runner(&source, &runner).unwrap();
+    Tmux::select_layout_with_runner(&Server::Default, "%7", "tiled", &runner)
+      .unwrap();
+    Tmux::switch_client_with_runner(&source, &runner).unwrap();
+
+    assert_eq!(
+      runner.commands(),
+      vec![
+        "kill-pane -t %4".to_string(),
+        "respawn-pane -k -t %4".to_string(),
+        "rename-window -t %4 -- agents".to_string(),
+        "break-pane -d -s %4 -t work:".to_string(),
+        "swap-pane -d -s %4 -t %7".to_string(),
+        "kill-window -t %4".to_string(),
+        "select-layout -t %7 tiled".to_string(),
+        "switch-client -t %4".to_string(),
+      ]
+    );
+  }
+
+  #[test]
+  fn capture_aggregates_panes_from_every_server() {
+    let runner = MockCommandRunner {
+      list_panes_output: format!("{}\n", pane("session1", 0, 0, "%0", "", "")),
+      ..Default::default()
+    };
+
+    let mut tmux = Tmux::new(Config {
+      servers: vec![Server::Default, Server::Name("work".into())],
+      ..Default::default()
+    });
+
+    tmux.capture_with_runner(&runner).unwrap();
+
+    assert_eq!(
+      tmux
+        .panes
+        .iter()
+        .map(|pane| pane.id.as_str())
+        .collect::<Vec<_>>(),
+      ["%0", "work/%0"]
+    );
+
+    assert_eq!(tmux.panes[1].server, Server::Name("work".into()));
+
+    assert!(
+      runner
+        .commands()
+        .contains(&"-L work capture-pane -t session1:0.0 -p -e".to_string())
+    );
+  }
+
+  #[test]
+  fn capture_skips_servers_that_fail_to_list() {
+    let runner = MockCommandRunner {
+      list_panes_output: format!("{}\n", pane("session1", 0, 0, "%0", "", "")),
+      list_panes_stderr: [
+        (
+          Server::Name("work".into()),
+          "error connecting to /tmp/tmux-0/work (Permission denied)\n".into(),
+        ),
+        (
+          Server::Path("/tmp/gone.sock".into()),
+          "error connecting to /tmp/gone.sock (No such file or directory)\n"
+            .into(),
+        ),
+      ]
+      .into(),
+      ..Default::default()
+    };
+
+    let mut tmux = Tmux::new(Config {
+      servers: vec![
+        Server::Default,
+        Server::Name("work".into()),
+        Server::Path("/tmp/gone.sock".into()),
+        Server::Name("play".into()),
+      ],
+      ..Default::default()
+    });
+
+    tmux.capture_with_runner(&runner).unwrap();
+
+    assert_eq!(
+      tmux
+        .panes
+        .iter()
+        .map(|pane| pane.id.as_str())
+        .collect::<Vec<_>>(),
+      ["%0", "play/%0"]
+    );
+
+    assert_eq!(
+      tmux.failures,
+      [
+        "failed to list panes of tmux server work",
+        "tmux server gone.sock is not running",
+      ]
+    );
+  }
+
+  #[test]
+  fn capture_fails_when_every_server_fails() {
+    let runner = MockCommandRunner {
+      list_panes_success: false,
+      ..Default::default()
+    };
+
+    let mut tmux = Tmux::new(Config {
+      servers: vec![Server::Name("work".into()), Server::Name("play".into())],
+      ..Default::default()
+    });
+
+    assert_eq!(
+      tmux.capture_with_runner(&runner).unwrap_err().to_string(),
+      "failed to list panes of tmux server work; \
+       failed to list panes of tmux server play"
+    );
+  }
+
+  #[test]
+  fn pane_commands_are_routed_to_the_pane_server() {
+    let runner = MockCommandRunner::default();
+
+    let server = Server::Path("/tmp/work.sock".into());
+
+    let (source, target) = (
+      Pane {
+        id: server.qualify("%4"),
+        server: server.clone(),
+        ..Default::default()
+      },
+      Pane {
+        id: "%7".to_string(),
+        ..Default::default()
+      },
+    );
+
+    Tmux::kill_pane_with_runner(&source, &runner).unwrap();
+
+    assert!(Tmux::swap_panes_with_runner(&source, &target, &runner).is_err());
+
+    assert_eq!(
+      runner.commands(),
+      vec!["-S /tmp/work.sock kill-pane -t %4".to_string()]
+    );
+  }
+
+  #[test]
+  fn spawn_returns_new_pane_id() {
+    let runner = MockCommandRunner::default();
+
+    let request = SpawnRequest {
+      command: "codex".into(),
+      directory: "/work".into(),
+      target: SpawnTarget::Window {
+        name: None,
+        session: None,
+      },
+    };
+
+    assert_eq!(
+      Tmux::spawn_with_runner(&Server::Default, &request, &runner).unwrap(),
+      "%42"
+    );
+
+    assert_eq!(
+      runner.commands(),
+      vec!["new-window -P -F #{pane_id} -c /work codex".to_string()]
+    );
+  }
+
+  #[test]
+  fn pane_management_failure_names_pane() {
+    struct FailingRunner;
+
+    impl CommandRunner for FailingRunner {
+      fn run(&self, _server: &Server, _arguments: &[&str]) -> Result<Output> {
+        Ok(Output {
+          status: exit_status(false),
+          stderr: vec![],
+          stdout: vec![],
+        })
+      }
+    }
+
+    let pane = Pane {
+      id: "%4".to_string(),
+      session: "work".to_string(),
+      window_index: 1,
+      ..Default::default()
+    };
+
+    assert_eq!(
+      Tmux::kill_pane_with_runner(&pane, &FailingRunner)
+        .unwrap_err()
+        .to_string(),
+      "failed to kill pane work:1.0"
+    );
+  }
+
   #[test]
   fn capture_pane_command_failure() {
     let mut capture_successes = BTreeMap::new();
@@ -822,7 +2029,7 @@ mod tests {
     struct InvalidUtf8Runner;
 
     impl CommandRunner for InvalidUtf8Runner {
-      fn run(&self, args: &[&str]) -> Result<Output> {
+      fn run(&self, _server: &Server, args: &[&str]) -> Result<Output> {
         match args[0] {
           "list-panes" => Ok(Output {
             status: exit_status(true),
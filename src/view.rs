@@ -0,0 +1,7 @@
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) enum View {
+  #[default]
+  Grid,
+  List,
+  Preview,
+}
@@ -0,0 +1,279 @@
+use super::*;
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct VirtualPane {
+  #[serde(default)]
+  command: Option<String>,
+  #[serde(default)]
+  file: Option<PathBuf>,
+  #[serde(default = "VirtualPane::default_interval")]
+  interval: u64,
+  name: String,
+}
+
+impl VirtualPane {
+  fn default_interval() -> u64 {
+    2
+  }
+
+  pub(crate) fn validate(&self) -> Result {
+    match (&self.command, &self.file) {
+      (Some(_), Some(_)) => {
+        bail!("virtual pane {} sets both command and file", self.name)
+      }
+      (None, None) => {
+        bail!("virtual pane {} needs a command or a file", self.name)
+      }
+      _ => Ok(()),
+    }
+  }
+}
+
+#[derive(Debug, Default)]
+struct Watch {
+  content: String,
+  last_run: Option<Instant>,
+  pending: Option<Receiver<String>>,
+}
+
+impl Watch {
+  fn poll(&mut self, command: &str, interval: Duration) {
+    if let Some(pending) = &self.pending {
+      match pending.try_recv() {
+        Ok(content) => {
+          self.content = content;
+          self.pending = None;
+        }
+        Err(TryRecvError::Disconnected) => self.pending = None,
+        Err(TryRecvError::Empty) => return,
+      }
+    }
+
+    if self
+      .last_run
+      .is_some_and(|last_run| last_run.elapsed() < interval)
+    {
+      return;
+    }
+
+    let (sender, receiver) = mpsc::channel();
+
+    let command = command.to_string();
+
+    thread::spawn(move || {
+      let content = match Command::new("sh").arg("-c").arg(&command).output() {
+        Ok(output) => format!(
+          "{}{}",
+          String::from_utf8_lossy(&output.stdout),
+          String::from_utf8_lossy(&output.stderr)
+        ),
+        Err(error) => format!("failed to run {command}: {error}"),
+      };
+
+      sender.send(content).ok();
+    });
+
+    self.last_run = Some(Instant::now());
+    self.pending = Some(receiver);
+  }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct VirtualPanes {
+  panes: Vec<(VirtualPane, Watch)>,
+}
+
+impl VirtualPanes {
+  const SESSION: &'static str = "@virtual";
+  const TAIL_BYTES: u64 = 64 * 1024;
+
+  pub(crate) fn new(panes: Vec<VirtualPane>) -> Self {
+    Self {
+      panes: panes
+        .into_iter()
+        .map(|pane| (pane, Watch::default()))
+        .collect(),
+    }
+  }
+
+  pub(crate) fn refresh(&mut self) -> Vec<Pane> {
+    self
+      .panes
+      .iter_mut()
+      .enumerate()
+      .map(|(window_index, (pane, watch))| {
+        let (command, path) = match (&pane.command, &pane.file) {
+          (Some(command), _) => {
+            watch.poll(command, Duration::from_secs(pane.interval));
+            (command.clone(), String::new())
+          }
+          (None, Some(file)) => {
+            let file = Workspace::join(Path::new(""), Some(file));
+            watch.content = Self::tail(&file);
+            ("tail".to_string(), file.display().to_string())
+          }
+          (None, None) => (String::new(), String::new()),
+        };
+
+        Pane {
+          command,
+          content: watch.content.clone(),
+          id: format!("{}/{window_index}", Self::SESSION),
+          is_virtual: true,
+          path,
+          session: Self::SESSION.to_string(),
+          window_index,
+          window_name: pane.name.clone(),
+          ..Default::default()
+        }
+      })
+      .collect()
+  }
+
+  fn tail(path: &Path) -> String {
+    let read = || -> io::Result<String> {
+      let mut file = File::open(path)?;
+
+      let start = file.metadata()?.len().saturating_sub(Self::TAIL_BYTES);
+
+      file.seek(SeekFrom::Start(start))?;
+
+      let mut bytes = Vec::new();
+
+      file.read_to_end(&mut bytes)?;
+
+      let content = String::from_utf8_lossy(&bytes).to_string();
+
+      Ok(if start > 0 {
+        content
+          .split_once('\n')
+          .map_or(content.clone(), |(_, rest)| rest.to_string())
+      } else {
+        content
+      })
+    };
+
+    read().unwrap_or_else(|error| {
+      format!("failed to read {}: {error}", path.display())
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn path(name: &str) -> PathBuf {
+    env::temp_dir().join(format!("spymux-virtual-{}-{name}", process::id()))
+  }
+
+  fn virtual_pane(command: Option<&str>, file: Option<&Path>) -> VirtualPane {
+    VirtualPane {
+      command: command.map(str::to_string),
+      file: file.map(Path::to_path_buf),
+      interval: 60,
+      name: "watch".into(),
+    }
+  }
+
+  #[test]
+  fn validate_requires_exactly_one_source() {
+    assert!(virtual_pane(Some("date"), None).validate().is_ok());
+    assert!(
+      virtual_pane(None, Some(Path::new("log")))
+        .validate()
+        .is_ok()
+    );
+    assert!(virtual_pane(None, None).validate().is_err());
+    assert!(
+      virtual_pane(Some("date"), Some(Path::new("log")))
+        .validate()
+        .is_err()
+    );
+  }
+
+  #[test]
+  fn file_panes_show_the_end_of_the_file() {
+    let path = path("tail");
+
+    let line = "x".repeat(1023);
+
+    fs::write(&path, format!("{line}\n").repeat(100) + "last\n").unwrap();
+
+    let mut panes =
+      VirtualPanes::new(vec![virtual_pane(None, Some(path.as_path()))]);
+
+    let pane = panes.refresh().remove(0);
+
+    assert!(pane.is_virtual);
+    assert_eq!(pane.id, "@virtual/0");
+    assert_eq!(pane.window_name, "watch");
+    assert!(pane.content.ends_with("last\n"));
+    assert!(pane.content.starts_with(&line));
+    assert!(pane.content.len() <= 64 * 1024);
+
+    fs::remove_file(path).unwrap();
+  }
+
+  #[test]
+  fn file_paths_expand_home() {
+    let Some(home) = env::var_os("HOME") else {
+      return;
+    };
+
+    let mut panes =
+      VirtualPanes::new(vec![virtual_pane(None, Some(Path::new("~/log")))]);
+
+    assert_eq!(
+      panes.refresh()[0].path,
+      PathBuf::from(home).join("log").display().to_string()
+    );
+  }
+
+  #[test]
+  fn virtual_panes_do_not_match_a_real_virtual_session() {
+    let real = Pane {
+      id: "%0".into(),
+      session: "virtual".into(),
+      ..Default::default()
+    };
+
+    let mut panes = VirtualPanes::new(vec![virtual_pane(Some("true"), None)]);
+
+    let pane = panes.refresh().remove(0);
+
+    assert_ne!(pane.id, real.id);
+    assert!(real.matches_target("virtual:0.0"));
+    assert!(!pane.matches_target("virtual:0.0"));
+    assert!(!pane.matches_target(&real.descriptor()));
+    assert!(pane.matches_target("@virtual/0"));
+  }
+
+  #[test]
+  fn missing_file_reports_error_as_content() {
+    let mut panes =
+      VirtualPanes::new(vec![virtual_pane(None, Some(&path("missing")))]);
+
+    assert!(panes.refresh()[0].content.starts_with("failed to read"));
+  }
+
+  #[test]
+  fn command_panes_show_command_output() {
+    let mut panes = VirtualPanes::new(vec![virtual_pane(
+      Some("echo hello; echo oops >&2"),
+      None,
+    )]);
+
+    let started = Instant::now();
+
+    let mut content = String::new();
+
+    while content.is_empty() && started.elapsed() < Duration::from_secs(5) {
+      content = panes.refresh().remove(0).content;
+      thread::sleep(Duration::from_millis(10));
+    }
+
+    assert_eq!(content, "hello\noops\n");
+  }
+}
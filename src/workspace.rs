@@ -0,0 +1,322 @@
+use super::*;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct WorkspacePane {
+  command: Option<String>,
+  directory: Option<PathBuf>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct WorkspaceWindow {
+  #[serde(default)]
+  command: Option<String>,
+  #[serde(default)]
+  directory: Option<PathBuf>,
+  #[serde(default)]
+  pub(crate) layout: Option<String>,
+  pub(crate) name: String,
+  #[serde(default)]
+  panes: Vec<WorkspacePane>,
+}
+
+impl WorkspaceWindow {
+  pub(crate) fn panes(&self, root: &Path) -> Vec<(String, String)> {
+    let window = Workspace::join(root, self.directory.as_deref());
+
+    let resolve = |pane: &WorkspacePane| {
+      (
+        pane
+          .command
+          .clone()
+          .or_else(|| self.command.clone())
+          .unwrap_or_default(),
+        Workspace::join(&window, pane.directory.as_deref())
+          .to_string_lossy()
+          .to_string(),
+      )
+    };
+
+    if self.panes.is_empty() {
+      vec![resolve(&WorkspacePane::default())]
+    } else {
+      self.panes.iter().map(resolve).collect()
+    }
+  }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct WorkspaceSession {
+  #[serde(skip)]
+  directory: PathBuf,
+  pub(crate) name: String,
+  #[serde(default)]
+  root: Option<PathBuf>,
+  pub(crate) windows: Vec<WorkspaceWindow>,
+}
+
+impl WorkspaceSession {
+  pub(crate) fn root(&self) -> PathBuf {
+    Workspace::join(&self.directory, self.root.as_deref())
+  }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Workspace {
+  pub(crate) sessions: Vec<WorkspaceSession>,
+}
+
+impl Workspace {
+  pub(crate) fn join(base: &Path, path: Option<&Path>) -> PathBuf {
+    let Some(path) = path else {
+      return base.to_path_buf();
+    };
+
+    match (path.strip_prefix("~"), env::var_os("HOME")) {
+      (Ok(rest), Some(home)) => PathBuf::from(home).join(rest),
+      _ => base.join(path),
+    }
+  }
+
+  pub(crate) fn load(workspace: &str) -> Result<Self> {
+    let path = Self::path(workspace)?;
+
+    let contents = fs::read_to_string(&path).with_context(|| {
+      format!("failed to read workspace file {}", path.display())
+    })?;
+
+    let mut workspace: Self = toml::from_str(&contents).with_context(|| {
+      format!("failed to parse workspace file {}", path.display())
+    })?;
+
+    workspace
+      .validate()
+      .with_context(|| format!("invalid workspace file {}", path.display()))?;
+
+    let directory = env::current_dir()
+      .context("failed to get current directory")?
+      .join(path.parent().unwrap_or(Path::new("")));
+
+    for session in &mut workspace.sessions {
+      session.directory.clone_from(&directory);
+    }
+
+    Ok(workspace)
+  }
+
+  fn path(workspace: &str) -> Result<PathBuf> {
+    let path = PathBuf::from(workspace);
+
+    if path.is_file() {
+      return Ok(path);
+    }
+
+    ConfigFile::directory()
+      .map(|directory| {
+        directory
+          .join("workspaces")
+          .join(format!("{workspace}.toml"))
+      })
+      .filter(|path| path.is_file())
+      .ok_or_else(|| anyhow!("unable to locate workspace {workspace}"))
+  }
+
+  fn validate(&self) -> Result {
+    if self.sessions.is_empty() {
+      bail!("workspace declares no sessions");
+    }
+
+    for session in &self.sessions {
+      if session.windows.is_empty() {
+        bail!("session {} declares no windows", session.name);
+      }
+
+      let mut names = HashSet::new();
+
+      for window in &session.windows {
+        if !names.insert(&window.name) {
+          bail!(
+            "session {} declares window {} more than once",
+            session.name,
+            window.name
+          );
+        }
+      }
+    }
+
+    Ok(())
+  }
+
+  pub(crate) fn windows(&self) -> BTreeSet<(String, String)> {
+    self
+      .sessions
+      .iter()
+      .flat_map(|session| {
+        session
+          .windows
+          .iter()
+          .map(|window| (session.name.clone(), window.name.clone()))
+      })
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn parse(contents: &str) -> Result<Workspace> {
+    let workspace: Workspace = toml::from_str(contents)?;
+    workspace.validate()?;
+    Ok(workspace)
+  }
+
+  #[test]
+  fn load_resolves_root_against_workspace_file() {
+    let directory =
+      env::temp_dir().join(format!("spymux-workspace-{}", process::id()));
+
+    fs::create_dir_all(&directory).unwrap();
+
+    let path = directory.join("api.toml");
+
+    fs::write(
+      &path,
+      "[[sessions]]\nname = \"api\"\nroot = \"src\"\n\n\
+       [[sessions.windows]]\nname = \"shell\"\n",
+    )
+    .unwrap();
+
+    let workspace = Workspace::load(path.to_str().unwrap()).unwrap();
+
+    assert_eq!(workspace.sessions[0].root(), directory.join("src"));
+
+    fs::remove_dir_all(directory).unwrap();
+  }
+
+  #[test]
+  fn windows_lists_session_and_window_names() {
+    let workspace = parse(
+      r#"
+        [[sessions]]
+        name = "agents"
+
+        [[sessions.windows]]
+        name = "api"
+
+        [[sessions.windows]]
+        name = "web"
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(
+      workspace.windows().into_iter().collect::<Vec<_>>(),
+      [
+        ("agents".to_string(), "api".to_string()),
+        ("agents".to_string(), "web".to_string())
+      ]
+    );
+  }
+
+  #[test]
+  fn panes_inherit_window_command_and_directory() {
+    let workspace = parse(
+      r#"
+        [[sessions]]
+        name = "agents"
+
+        [[sessions.windows]]
+        name = "api"
+        command = "claude"
+        directory = "api"
+
+        [[sessions.windows.panes]]
+
+        [[sessions.windows.panes]]
+        command = "cargo watch"
+        directory = "/tmp"
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(
+      workspace.sessions[0].windows[0].panes(Path::new("/work")),
+      [
+        ("claude".to_string(), "/work/api".to_string()),
+        ("cargo watch".to_string(), "/tmp".to_string())
+      ]
+    );
+  }
+
+  #[test]
+  fn window_without_panes_has_one_pane() {
+    let workspace = parse(
+      r#"
+        [[sessions]]
+        name = "agents"
+
+        [[sessions.windows]]
+        name = "shell"
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(
+      workspace.sessions[0].windows[0].panes(Path::new("/work")),
+      [(String::new(), "/work".to_string())]
+    );
+  }
+
+  #[test]
+  fn validate_rejects_empty_and_duplicate_windows() {
+    assert!(parse("sessions = []").is_err());
+
+    assert!(
+      parse(
+        r#"
+          [[sessions]]
+          name = "agents"
+          windows = []
+        "#
+      )
+      .is_err()
+    );
+
+    assert!(
+      parse(
+        r#"
+          [[sessions]]
+          name = "agents"
+
+          [[sessions.windows]]
+          name = "api"
+
+          [[sessions.windows]]
+          name = "api"
+        "#
+      )
+      .is_err()
+    );
+  }
+
+  #[test]
+  fn unknown_fields_are_rejected() {
+    assert!(
+      parse(
+        r#"
+          [[sessions]]
+          name = "agents"
+
+          [[sessions.windows]]
+          name = "api"
+          commmand = "claude"
+        "#
+      )
+      .is_err()
+    );
+  }
+}